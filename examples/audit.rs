@@ -0,0 +1,83 @@
+use apache_avro::Schema;
+use serde::Serialize;
+use serde_avro_bytes::audit::audit;
+
+#[derive(Serialize)]
+struct Header {
+    checksum: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::bytes")]
+    signature: Vec<u8>,
+}
+
+#[derive(Serialize)]
+enum Body {
+    // forgotten annotation
+    Raw(Vec<u8>),
+    #[allow(unused)]
+    Text(String),
+}
+
+/// Schema of the `type`/`value` record `apache_avro::to_value` makes of a `Body`.
+static BODY: &str = r#"{
+    "name": "Body",
+    "type": "record",
+    "fields": [
+        {"name": "type", "type": {"name": "BodyType", "type": "enum", "symbols": ["Raw", "Text"]}},
+        {"name": "value", "type": [{"type": "array", "items": "int"}, "string"]}
+    ]
+}"#;
+
+#[derive(Serialize)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    // forgotten annotations
+    payload: Vec<u8>,
+    trailer: Vec<u8>,
+    header: Header,
+    body: Body,
+    count: i32,
+}
+
+fn main() {
+    let header = serde_avro_bytes::schema::record(
+        "Header",
+        [
+            ("checksum", Schema::Array(Box::new(Schema::Int))),
+            ("signature", serde_avro_bytes::bytes::schema()),
+        ],
+    )
+    .expect("valid header schema");
+    let schema = serde_avro_bytes::schema::record(
+        "Record",
+        [
+            ("key", serde_avro_bytes::bytes::schema()),
+            ("payload", Schema::Array(Box::new(Schema::Int))),
+            ("trailer", Schema::Array(Box::new(Schema::Int))),
+            ("header", header),
+            ("body", Schema::parse_str(BODY).expect("valid body schema")),
+            ("count", Schema::Int),
+        ],
+    )
+    .expect("valid record schema");
+
+    let record = Record {
+        key: vec![1, 2],
+        payload: vec![3, 4],
+        trailer: vec![],
+        header: Header {
+            checksum: vec![5],
+            signature: vec![6],
+        },
+        body: Body::Raw(vec![7]),
+        count: 1,
+    };
+
+    // the empty `trailer` is only found through the schema, and the payload of an
+    // enum variant is named after the variant in both the value and the schema
+    let findings = audit(&record, &schema).expect("audited record");
+    assert_eq!(
+        findings,
+        ["body.Raw", "header.checksum", "payload", "trailer"]
+    );
+}
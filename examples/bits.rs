@@ -0,0 +1,77 @@
+use apache_avro::{types::Value, AvroSchema};
+use bitvec::{bitvec, order::Lsb0, vec::BitVec};
+use fixedbitset::FixedBitSet;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Features {
+    #[serde(with = "serde_avro_bytes::extra::bitvec")]
+    flags: BitVec<u8>,
+    #[serde(with = "serde_avro_bytes::extra::bitvec::option")]
+    experiments: Option<BitVec<u8>>,
+    #[serde(with = "serde_avro_bytes::extra::fixedbitset")]
+    bloom: FixedBitSet,
+}
+
+impl AvroSchema for Features {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Features",
+            [
+                ("flags", serde_avro_bytes::extra::bitvec::schema()),
+                (
+                    "experiments",
+                    serde_avro_bytes::extra::bitvec::option::schema(),
+                ),
+                ("bloom", serde_avro_bytes::extra::fixedbitset::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let mut bloom = FixedBitSet::with_capacity(1000);
+    bloom.insert(3);
+    bloom.insert(999);
+    let features = Features {
+        flags: bitvec![u8, Lsb0; 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
+        experiments: Some(BitVec::new()),
+        bloom,
+    };
+
+    let value = apache_avro::to_value(&features).expect("avro value");
+    let Value::Record(fields) = &value else {
+        panic!("record expected")
+    };
+    // bit length 11 followed by two packed bytes
+    assert_eq!(
+        fields[0].1,
+        Value::Bytes(vec![11, 0b0000_1101, 0b0000_0011])
+    );
+
+    let schema = Features::get_schema();
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    let decoded = apache_avro::from_value::<Features>(&decoded).expect("features");
+    assert_eq!(decoded, features);
+    assert_eq!(decoded.flags.len(), 11);
+    assert_eq!(decoded.bloom.len(), 1000);
+
+    // a vector built from a sub-slice starts in the middle of its first byte
+    let full = bitvec![u8, Lsb0; 1, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1];
+    let features = Features {
+        flags: BitVec::from_bitslice(&full[3..]),
+        experiments: None,
+        bloom: FixedBitSet::new(),
+    };
+    assert_eq!(features.flags, bitvec![u8, Lsb0; 0, 1, 0, 0, 1, 1, 0, 1]);
+    let value = apache_avro::to_value(&features).expect("avro value");
+    let Value::Record(fields) = &value else {
+        panic!("record expected")
+    };
+    assert_eq!(fields[0].1, Value::Bytes(vec![8, 0b1011_0010]));
+    let decoded = apache_avro::from_value::<Features>(&value).expect("features");
+    assert_eq!(decoded, features);
+}
@@ -3,7 +3,7 @@ use std::{
     io::Cursor,
 };
 
-use apache_avro::Schema;
+use apache_avro::{types::Value, Schema};
 use bstr::BString;
 use serde::{Deserialize, Serialize};
 
@@ -107,18 +107,18 @@ pub struct Record {
 }
 
 fn avro_encode_decode(schema: &Schema, record: &Record) -> Record {
-    let value = apache_avro::to_value(&record).expect("avro value");
-    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let value = apache_avro::to_value(record).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(schema, value).expect("encoded value");
     let mut reader = Cursor::new(encoded);
     let value =
-        apache_avro::from_avro_datum(&schema, &mut reader, Some(&schema)).expect("decoded record");
+        apache_avro::from_avro_datum(schema, &mut reader, Some(schema)).expect("decoded record");
     apache_avro::from_value::<Record>(&value).expect("record")
 }
 
 static PARTIAL_UTF8: &[u8] = b"hello \xF4\x8F\xBF";
 
 fn main() {
-    let schema = apache_avro::Schema::parse_str(&RECORD).expect("valid avro schema");
+    let schema = apache_avro::Schema::parse_str(RECORD).expect("valid avro schema");
 
     let record = Record::default();
     assert_eq!(record, avro_encode_decode(&schema, &record));
@@ -149,4 +149,19 @@ fn main() {
         ])),
     };
     assert_eq!(record, avro_encode_decode(&schema, &record));
+
+    // duplicate keys are refused with the index of the entry repeating the key
+    let pair = Value::Record(vec![
+        ("key".to_string(), Value::Bytes(b"a".to_vec())),
+        ("value".to_string(), Value::Bytes(b"1".to_vec())),
+    ]);
+    let mut value = apache_avro::to_value(Record::default()).expect("avro value");
+    let Value::Record(fields) = &mut value else {
+        panic!("record expected")
+    };
+    fields[4].1 = Value::Array(vec![pair.clone(), pair]);
+    let err = apache_avro::from_value::<Record>(&value).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("at index 1: unexpected duplicate key: 0x61"));
 }
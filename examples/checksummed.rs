@@ -0,0 +1,64 @@
+use apache_avro::{types::Value, Schema};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Message {
+    #[serde(with = "serde_avro_bytes::checksummed::crc32c")]
+    payload: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::checksummed::crc32c::option")]
+    signature: Option<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::checksummed::xxh3::list")]
+    chunks: Vec<Vec<u8>>,
+}
+
+fn schema() -> Schema {
+    serde_avro_bytes::schema::record(
+        "Message",
+        [
+            ("payload", serde_avro_bytes::checksummed::crc32c::schema()),
+            (
+                "signature",
+                serde_avro_bytes::checksummed::crc32c::option::schema(),
+            ),
+            (
+                "chunks",
+                serde_avro_bytes::checksummed::xxh3::list::schema(),
+            ),
+        ],
+    )
+    .expect("valid record schema")
+}
+
+fn main() {
+    let schema = schema();
+    let message = Message {
+        payload: b"hello".to_vec(),
+        signature: None,
+        chunks: vec![b"first".to_vec(), b"second".to_vec()],
+    };
+
+    let value = apache_avro::to_value(&message).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Message>(&decoded).expect("message"),
+        message
+    );
+
+    // flip a bit of the second chunk
+    let Value::Record(mut fields) = decoded else {
+        panic!("record expected")
+    };
+    let Value::Array(chunks) = &mut fields[2].1 else {
+        panic!("array expected")
+    };
+    let Value::Bytes(chunk) = &mut chunks[1] else {
+        panic!("bytes expected")
+    };
+    chunk[0] ^= 1;
+    let err = apache_avro::from_value::<Message>(&Value::Record(fields))
+        .expect_err("corrupted chunk")
+        .to_string();
+    assert!(err.contains("at index 1") && err.contains("checksum mismatch"));
+}
@@ -0,0 +1,57 @@
+use apache_avro::AvroSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Document {
+    #[serde(with = "serde_avro_bytes::compressed::zstd")]
+    body: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::compressed::deflate::option")]
+    thumbnail: Option<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::compressed::snappy")]
+    raw: Vec<u8>,
+}
+
+impl AvroSchema for Document {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Document",
+            [
+                ("body", serde_avro_bytes::compressed::zstd::schema()),
+                (
+                    "thumbnail",
+                    serde_avro_bytes::compressed::deflate::option::schema(),
+                ),
+                ("raw", serde_avro_bytes::compressed::snappy::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let document = Document {
+        body: b"lorem ipsum ".repeat(1000),
+        thumbnail: Some(vec![0; 4096]),
+        raw: b"abcabcabc".repeat(100),
+    };
+
+    let schema = Document::get_schema();
+    let value = apache_avro::to_value(&document).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    assert!(encoded.len() < 500);
+
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Document>(&decoded).expect("document"),
+        document
+    );
+
+    // the announced length is checked before decompressing anything
+    let err = serde_avro_bytes::compressed::with_limit(1024, || {
+        apache_avro::from_value::<Document>(&decoded).expect_err("limit exceeded")
+    });
+    assert!(err
+        .to_string()
+        .contains("length 12000 exceeds the limit of 1024 bytes"));
+}
@@ -0,0 +1,42 @@
+use apache_avro::{AvroSchema, Schema};
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::confluent::{self, InMemoryRegistry, SchemaResolver};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::list::option")]
+    values: Option<Vec<Vec<u8>>>,
+}
+
+impl AvroSchema for Record {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record(
+            "Record",
+            [
+                ("key", serde_avro_bytes::bytes::schema()),
+                ("values", serde_avro_bytes::list::option::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let mut registry = InMemoryRegistry::new();
+    let id = confluent::register::<Record>(&mut registry, "records-value").expect("registered");
+    assert_eq!(registry.latest("records-value"), Some(id));
+
+    let record = Record {
+        key: vec![0, 1, 3],
+        values: Some(vec![vec![4, 5], vec![6]]),
+    };
+    let schema = registry.resolve(id).expect("registered schema");
+    let message = confluent::encode(id, &schema, &record).expect("framed record");
+    assert_eq!(message[0], confluent::MAGIC_BYTE);
+    assert_eq!(&message[1..5], &id.to_be_bytes());
+
+    let decoded = confluent::decode::<Record>(&registry, &message).expect("decoded record");
+    assert_eq!(decoded, record);
+}
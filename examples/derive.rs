@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, HashMap};
+
+use apache_avro::{types::Value, AvroSchema, Schema};
+use bstr::BString;
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::avro_bytes;
+
+/// Type aliases are not seen through by the attribute, `as` names the aliased type.
+type Digest = Vec<u8>;
+
+#[avro_bytes]
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    key: Vec<u8>,
+    key2: Option<Vec<u8>>,
+    key3: HashMap<Vec<u8>, Vec<u8>>,
+    key4: Option<BTreeMap<Vec<u8>, Vec<u8>>>,
+    key5: Vec<Vec<u8>>,
+    key6: Option<Vec<Vec<u8>>>,
+    name: BString,
+    names: Option<Vec<BString>>,
+    range: Option<(Vec<u8>, Vec<u8>)>,
+    #[avro_bytes(skip)]
+    ints: Vec<u8>,
+    count: i32,
+    #[avro_bytes(as = "Vec<u8>")]
+    digest: Digest,
+}
+
+impl AvroSchema for Record {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record(
+            "Record",
+            [
+                ("key", serde_avro_bytes::bytes::schema()),
+                ("key2", serde_avro_bytes::bytes::option::schema()),
+                ("key3", serde_avro_bytes::hashmap::schema()),
+                ("key4", serde_avro_bytes::btreemap::option::schema()),
+                ("key5", serde_avro_bytes::list::schema()),
+                ("key6", serde_avro_bytes::list::option::schema()),
+                ("name", serde_avro_bytes::extra::bstr::schema()),
+                (
+                    "names",
+                    serde_avro_bytes::extra::bstr::list::option::schema(),
+                ),
+                ("range", serde_avro_bytes::tuple::option::schema()),
+                ("ints", Schema::Array(Box::new(Schema::Int))),
+                ("count", Schema::Int),
+                ("digest", serde_avro_bytes::bytes::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let record = Record {
+        key: vec![0, 1, 3],
+        key2: Some(vec![4, 5, 6]),
+        key3: HashMap::from([(vec![1, 5, 6], vec![7, 8, 9])]),
+        key4: Some(BTreeMap::from([(vec![10, 11, 12], vec![13, 1, 48])])),
+        key5: vec![vec![1, 5, 6, 7], vec![4, 8, 2, 6]],
+        key6: None,
+        name: BString::from("name"),
+        names: Some(vec![BString::from(b"hello \xF4\x8F\xBF".as_slice())]),
+        range: Some((vec![0], vec![255])),
+        ints: vec![1, 2],
+        count: 3,
+        digest: vec![0xab; 4],
+    };
+
+    let value = apache_avro::to_value(&record).expect("avro value");
+    let Value::Record(fields) = &value else {
+        panic!("record expected")
+    };
+    assert_eq!(fields[0].1, Value::Bytes(vec![0, 1, 3]));
+    assert!(matches!(fields[9].1, Value::Array(_)));
+    assert_eq!(fields[11].1, Value::Bytes(vec![0xab; 4]));
+
+    let schema = Record::get_schema();
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Record>(&decoded).expect("record"),
+        record
+    );
+}
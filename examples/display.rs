@@ -0,0 +1,77 @@
+use std::collections::{BTreeMap, HashMap};
+
+use apache_avro::AvroSchema;
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::{AvroByteSlice, AvroBytes, AvroBytesMap};
+
+/// Field types printing readably, without `with` annotations.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    key: AvroBytes,
+    headers: AvroBytesMap,
+    // any container of `AvroBytes` is encoded with `bytes` items
+    tags: Vec<AvroBytes>,
+    parent: Option<AvroBytes>,
+}
+
+impl AvroSchema for Record {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Record",
+            [
+                ("key", serde_avro_bytes::bytes::schema()),
+                ("headers", serde_avro_bytes::hashmap::schema()),
+                ("tags", serde_avro_bytes::list::schema()),
+                ("parent", serde_avro_bytes::bytes::option::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let record = Record {
+        key: AvroBytes(b"user:\x00\x01\"42\"".to_vec()),
+        headers: AvroBytesMap(HashMap::from([
+            (b"trace".to_vec(), vec![0xab; 40]),
+            (b"content-type".to_vec(), b"text/plain".to_vec()),
+        ])),
+        tags: vec![b"new".to_vec().into(), AvroBytes::from(&b"\xff"[..])],
+        parent: None,
+    };
+
+    assert_eq!(record.key.to_string(), r#"b"user:\x00\x01\"42\"""#);
+    assert_eq!(format!("{:#}", record.key), "0x757365723a000122343222");
+    assert_eq!(format!("{:.4}", record.key), r#"b"user"... (11 bytes)"#);
+    assert_eq!(
+        format!("{:#.2}", record.headers),
+        r#"{0x636f... (12 bytes): 0x7465... (10 bytes), 0x7472... (5 bytes): 0xabab... (40 bytes)}"#
+    );
+
+    // encoded exactly like the `bytes` and `hashmap` modules
+    let schema = Record::get_schema();
+    let value = apache_avro::to_value(&record).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Record>(&decoded).expect("record"),
+        record
+    );
+
+    // `AvroBytes` hashes and compares like `[u8]`, so maps keyed by it are looked up with slices
+    let counts = BTreeMap::from([(record.key.clone(), 1), (record.tags[0].clone(), 2)]);
+    assert_eq!(counts.get(&b"new"[..]), Some(&2));
+    assert!(record.tags[1].starts_with(&[0xff]));
+
+    // Avro map keys are strings, such a map is encoded as an `AvroBytesMap` instead
+    assert!(apache_avro::to_value(&counts).is_err());
+
+    // `AvroByteSlice` borrows the bytes of an `AvroBytes` and serializes the same way
+    let slice = AvroByteSlice::from(&record.key);
+    assert_eq!(slice.to_string(), record.key.to_string());
+    assert_eq!(
+        apache_avro::to_value(slice).expect("avro value"),
+        apache_avro::to_value(&record.key).expect("avro value")
+    );
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use apache_avro::AvroSchema;
+use serde::{Deserialize, Serialize};
+
+/// Sub-document evolving outside of the schema process.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+struct Attributes {
+    color: String,
+    sizes: Vec<u32>,
+    extra: BTreeMap<String, String>,
+}
+
+/// Sub-document with a schema of its own.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+struct Location {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl AvroSchema for Location {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Location",
+            [
+                ("latitude", apache_avro::Schema::Double),
+                ("longitude", apache_avro::Schema::Double),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Product {
+    id: i64,
+    #[serde(with = "serde_avro_bytes::embedded::json")]
+    attributes: Attributes,
+    #[serde(with = "serde_avro_bytes::embedded::msgpack::option")]
+    previous: Option<Attributes>,
+    #[serde(with = "serde_avro_bytes::embedded::bincode")]
+    tags: Vec<String>,
+    #[serde(with = "serde_avro_bytes::embedded::avro")]
+    warehouse: Location,
+}
+
+impl AvroSchema for Product {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Product",
+            [
+                ("id", apache_avro::Schema::Long),
+                ("attributes", serde_avro_bytes::embedded::json::schema()),
+                (
+                    "previous",
+                    serde_avro_bytes::embedded::msgpack::option::schema(),
+                ),
+                ("tags", serde_avro_bytes::embedded::bincode::schema()),
+                ("warehouse", serde_avro_bytes::embedded::avro::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let attributes = Attributes {
+        color: "red".to_string(),
+        sizes: vec![38, 40],
+        extra: BTreeMap::from([("material".to_string(), "wool".to_string())]),
+    };
+    let product = Product {
+        id: 1,
+        attributes: attributes.clone(),
+        previous: Some(attributes),
+        tags: vec!["winter".to_string()],
+        warehouse: Location {
+            latitude: 48.85,
+            longitude: 2.35,
+        },
+    };
+
+    let schema = Product::get_schema();
+    let value = apache_avro::to_value(&product).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Product>(&decoded).expect("product"),
+        product
+    );
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use apache_avro::{types::Value, AvroSchema};
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::encrypted::{with_associated_data, with_cipher, Aes256GcmCipher, NoopCipher};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Customer {
+    id: i64,
+    #[serde(with = "serde_avro_bytes::encrypted")]
+    email: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::encrypted::option")]
+    phone: Option<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::encrypted::list")]
+    addresses: Vec<Vec<u8>>,
+}
+
+impl AvroSchema for Customer {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Customer",
+            [
+                ("id", apache_avro::Schema::Long),
+                ("email", serde_avro_bytes::encrypted::schema()),
+                ("phone", serde_avro_bytes::encrypted::option::schema()),
+                ("addresses", serde_avro_bytes::encrypted::list::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let customer = Customer {
+        id: 42,
+        email: b"jane@example.com".to_vec(),
+        phone: Some(b"+33 1 23 45 67 89".to_vec()),
+        addresses: vec![b"1 rue de la Paix".to_vec()],
+    };
+    let schema = Customer::get_schema();
+
+    // without a cipher in scope, encrypted fields cannot be written
+    assert!(apache_avro::to_value(&customer).is_err());
+
+    let cipher = Arc::new(Aes256GcmCipher::new(&[7; 32]));
+    let encoded = with_cipher(cipher.clone(), || {
+        let value = apache_avro::to_value(&customer).expect("avro value");
+        apache_avro::to_avro_datum(&schema, value).expect("encoded value")
+    });
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+
+    let Value::Record(fields) = &decoded else {
+        panic!("record expected")
+    };
+    assert_ne!(fields[1].1, Value::Bytes(customer.email.clone()));
+
+    let decrypted = with_cipher(cipher.clone(), || {
+        apache_avro::from_value::<Customer>(&decoded).expect("customer")
+    });
+    assert_eq!(decrypted, customer);
+
+    // the wrong key fails authentication
+    let err = with_cipher(Arc::new(Aes256GcmCipher::new(&[8; 32])), || {
+        apache_avro::from_value::<Customer>(&decoded).expect_err("wrong key")
+    });
+    assert!(err.to_string().contains("field cipher failed"));
+
+    // bound to the id of its record, an email copied into another record does not decrypt
+    let encrypt = |customer: &Customer| {
+        with_cipher(cipher.clone(), || {
+            with_associated_data(&customer.id.to_be_bytes(), || {
+                apache_avro::to_value(customer).expect("avro value")
+            })
+        })
+    };
+    let decrypt = |value: &Value| {
+        let Value::Record(fields) = value else {
+            panic!("record expected")
+        };
+        let Value::Long(id) = fields[0].1 else {
+            panic!("long expected")
+        };
+        with_cipher(cipher.clone(), || {
+            with_associated_data(&id.to_be_bytes(), || {
+                apache_avro::from_value::<Customer>(value)
+            })
+        })
+    };
+    let other = Customer {
+        id: 43,
+        email: b"john@example.com".to_vec(),
+        phone: None,
+        addresses: vec![],
+    };
+    let mut first = encrypt(&customer);
+    let second = encrypt(&other);
+    assert_eq!(decrypt(&first).expect("customer"), customer);
+    assert_eq!(decrypt(&second).expect("customer"), other);
+
+    let (Value::Record(first_fields), Value::Record(second_fields)) = (&mut first, &second) else {
+        panic!("records expected")
+    };
+    first_fields[1].1 = second_fields[1].1.clone();
+    let err = decrypt(&first).expect_err("pasted ciphertext");
+    assert!(err.to_string().contains("field cipher failed"));
+
+    // the no-op cipher stores the plaintext
+    let value = with_cipher(Arc::new(NoopCipher), || {
+        apache_avro::to_value(&customer).expect("avro value")
+    });
+    let Value::Record(fields) = value else {
+        panic!("record expected")
+    };
+    assert_eq!(fields[1].1, Value::Bytes(customer.email));
+}
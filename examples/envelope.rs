@@ -0,0 +1,102 @@
+use apache_avro::{types::Value, AvroSchema, Schema};
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::{envelope::AvroEnvelope, single_object::SchemaTable};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Login {
+    user: String,
+    #[serde(with = "serde_avro_bytes::bytes")]
+    address: Vec<u8>,
+}
+
+impl AvroSchema for Login {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record(
+            "Login",
+            [
+                ("user", Schema::String),
+                ("address", serde_avro_bytes::bytes::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Purchase {
+    amount: i64,
+}
+
+impl AvroSchema for Purchase {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record("Purchase", [("amount", Schema::Long)])
+            .expect("valid record schema")
+    }
+}
+
+/// Outer record carrying events of any registered type.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Envelope {
+    source: String,
+    event: AvroEnvelope,
+}
+
+impl AvroSchema for Envelope {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record(
+            "Envelope",
+            [
+                ("source", Schema::String),
+                ("event", serde_avro_bytes::envelope::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let mut table = SchemaTable::new();
+    let login_fingerprint = table.insert_schema_of::<Login>();
+    table.insert_schema_of::<Purchase>();
+
+    let login = Login {
+        user: "jane".to_string(),
+        address: vec![127, 0, 0, 1],
+    };
+    let events = [
+        Envelope {
+            source: "auth".to_string(),
+            event: AvroEnvelope::encode(&Login::get_schema(), &login).expect("login event"),
+        },
+        Envelope {
+            source: "shop".to_string(),
+            event: AvroEnvelope::encode(&Purchase::get_schema(), &Purchase { amount: 42 })
+                .expect("purchase event"),
+        },
+    ];
+
+    let schema = Envelope::get_schema();
+    let mut decoded = vec![];
+    for envelope in &events {
+        let value = apache_avro::to_value(envelope).expect("avro value");
+        let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+        let value = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+            .expect("decoded value");
+        decoded.push(apache_avro::from_value::<Envelope>(&value).expect("envelope"));
+    }
+    assert_eq!(decoded, events);
+
+    // the fingerprint tells which type to decode
+    assert_eq!(decoded[0].event.fingerprint(), login_fingerprint);
+    assert_eq!(
+        decoded[0].event.decode::<Login>(&table).expect("login"),
+        login
+    );
+    assert_eq!(
+        decoded[1].event.decode_value(&table).expect("purchase"),
+        Value::Record(vec![("amount".to_string(), Value::Long(42))])
+    );
+
+    // unknown schemas are reported
+    assert!(decoded[0].event.decode_value(&SchemaTable::new()).is_err());
+}
@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, HashMap};
+
+use apache_avro::types::Value;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::hashmap")]
+    map: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct SortedRecord {
+    #[serde(with = "serde_avro_bytes::btreemap")]
+    map: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+fn pair(key: &[u8], value: Value) -> Value {
+    Value::Record(vec![
+        ("key".to_string(), Value::Bytes(key.to_vec())),
+        ("value".to_string(), value),
+    ])
+}
+
+fn record(pairs: Vec<Value>) -> Value {
+    Value::Record(vec![("map".to_string(), Value::Array(pairs))])
+}
+
+fn main() {
+    // the entry index and its key locate the failure, the Avro prefix appears once
+    let value = record(vec![
+        pair(b"a", Value::Bytes(b"1".to_vec())),
+        pair(b"b", Value::Bytes(b"2".to_vec())),
+        pair(b"broken", Value::Int(5)),
+    ]);
+    let err = apache_avro::from_value::<Record>(&value)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("at index 2: at key 0x62726f6b656e: Expected a String|Bytes|Fixed"));
+    assert_eq!(err.matches("Failed to deserialize Avro value").count(), 1);
+
+    // duplicate keys are refused instead of silently overwriting the first entry
+    let value = record(vec![
+        pair(b"a", Value::Bytes(b"1".to_vec())),
+        pair(b"a", Value::Bytes(b"2".to_vec())),
+    ]);
+    let err = apache_avro::from_value::<SortedRecord>(&value)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("at index 1: unexpected duplicate key: 0x61"));
+    assert_eq!(err.matches("Failed to deserialize Avro value").count(), 1);
+}
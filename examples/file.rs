@@ -0,0 +1,70 @@
+use std::collections::{BTreeMap, HashMap};
+
+use apache_avro::{AvroSchema, Codec, Schema};
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::file::AvroFile;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::bytes::option")]
+    key2: Option<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::hashmap")]
+    key3: HashMap<Vec<u8>, Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::btreemap::option")]
+    key4: Option<BTreeMap<Vec<u8>, Vec<u8>>>,
+    #[serde(with = "serde_avro_bytes::list")]
+    key5: Vec<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::tuple")]
+    range: (Vec<u8>, Vec<u8>),
+    #[serde(with = "serde_avro_bytes::tuple::list::option")]
+    ranges: Option<Vec<(Vec<u8>, Vec<u8>)>>,
+}
+
+impl AvroSchema for Record {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record(
+            "Record",
+            [
+                ("key", serde_avro_bytes::bytes::schema()),
+                ("key2", serde_avro_bytes::bytes::option::schema()),
+                ("key3", serde_avro_bytes::hashmap::schema()),
+                ("key4", serde_avro_bytes::btreemap::option::schema()),
+                ("key5", serde_avro_bytes::list::schema()),
+                ("range", serde_avro_bytes::tuple::schema()),
+                ("ranges", serde_avro_bytes::tuple::list::option::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let records = vec![
+        Record {
+            key: vec![0, 1, 3],
+            key2: Some(vec![4, 5, 6]),
+            key3: HashMap::from([(vec![1, 5, 6], vec![7, 8, 9])]),
+            key4: Some(BTreeMap::from([(vec![10, 11, 12], vec![13, 1, 48])])),
+            key5: vec![vec![1, 5, 6, 7], vec![4, 8, 2, 6]],
+            range: (vec![0], vec![255]),
+            ranges: Some(vec![(vec![0], vec![16]), (vec![16], vec![32])]),
+        },
+        Record {
+            key: vec![],
+            key2: None,
+            key3: HashMap::new(),
+            key4: None,
+            key5: vec![],
+            range: (vec![], vec![]),
+            ranges: None,
+        },
+    ];
+
+    let file = AvroFile::<Record>::default().with_codec(Codec::Deflate);
+    let encoded = file.write_all(Vec::new(), &records).expect("written file");
+    let decoded = file.read_all(encoded.as_slice()).expect("read file");
+
+    assert_eq!(decoded, records);
+}
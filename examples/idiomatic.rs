@@ -79,6 +79,26 @@ struct Record {
     key6: Option<Vec<Vec<u8>>>,
 }
 
+static UUID_SCHEMA: &str = r#"{
+  "name": "UuidRecord",
+  "type": "record",
+  "fields": [
+      {
+          "type": {
+              "type": "string",
+              "logicalType": "uuid"
+          },
+          "name": "id"
+      }
+  ]
+}"#;
+
+#[derive(Deserialize)]
+struct UuidRecord {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    id: Vec<u8>,
+}
+
 fn main() {
     let map = BTreeMap::from([
         (vec![1, 5, 6], vec![7, 8, 9]),
@@ -102,7 +122,7 @@ fn main() {
     };
 
     // encoding
-    let schema = apache_avro::Schema::parse_str(&SCHEMA).unwrap();
+    let schema = apache_avro::Schema::parse_str(SCHEMA).unwrap();
     let avro_value = apache_avro::to_value(&record).unwrap();
     let encoded = apache_avro::to_avro_datum(&schema, avro_value).unwrap();
 
@@ -112,4 +132,18 @@ fn main() {
     let result = apache_avro::from_value::<Record>(&decoding_data.unwrap()).unwrap();
 
     assert_eq!(result, record);
+
+    // a uuid is decoded as its 16 bytes
+    let schema = apache_avro::Schema::parse_str(UUID_SCHEMA).unwrap();
+    let mut encoded = vec![72];
+    encoded.extend_from_slice(b"67e55044-10b1-426f-9247-bb680e5fe0c8");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None).unwrap();
+    let result = apache_avro::from_value::<UuidRecord>(&decoded).unwrap();
+    assert_eq!(
+        result.id,
+        [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8
+        ]
+    );
 }
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+
+use apache_avro::{Codec, Reader, Schema, Writer};
+use serde::{Deserialize, Serialize};
+
+static LEGACY: &str = r#"{
+    "name": "Record",
+    "type": "record",
+    "fields": [
+        {
+            "name": "key",
+            "type": {
+                "type": "array",
+                "items": "int"
+            }
+        },
+        {
+            "name": "option",
+            "type": [
+                "null",
+                {
+                    "type": "array",
+                    "items": "int"
+                }
+            ]
+        }
+    ]
+}"#;
+
+#[derive(Serialize)]
+struct LegacyRecord {
+    key: Vec<u8>,
+    option: Option<Vec<u8>>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::bytes::option")]
+    option: Option<Vec<u8>>,
+}
+
+static LEGACY_UNION: &str = r#"{
+    "name": "Event",
+    "type": "record",
+    "fields": [
+        {
+            "name": "payload",
+            "type": [
+                "null",
+                {
+                    "name": "Created",
+                    "type": "record",
+                    "fields": [{"name": "key", "type": {"type": "array", "items": "int"}}]
+                },
+                {
+                    "name": "Deleted",
+                    "type": "record",
+                    "fields": [{"name": "key", "type": {"type": "array", "items": "int"}}]
+                },
+                {
+                    "name": "Renamed",
+                    "type": "record",
+                    "fields": [{"name": "name", "type": "string"}]
+                }
+            ]
+        }
+    ]
+}"#;
+
+static LEGACY_SHARED: &str = r#"{
+    "name": "Message",
+    "type": "record",
+    "fields": [
+        {
+            "name": "h",
+            "type": {
+                "name": "Header",
+                "type": "record",
+                "fields": [{"name": "k", "type": {"type": "array", "items": "int"}}]
+            }
+        },
+        {"name": "h2", "type": "Header"},
+        {
+            "name": "items",
+            "type": {
+                "type": "array",
+                "items": {
+                    "name": "Item",
+                    "type": "record",
+                    "fields": [{"name": "k", "type": {"type": "array", "items": "int"}}]
+                }
+            }
+        },
+        {"name": "index", "type": {"type": "map", "values": "Item"}}
+    ]
+}"#;
+
+#[derive(Serialize)]
+struct LegacyHeader {
+    k: Vec<i32>,
+}
+
+#[derive(Serialize)]
+struct LegacyMessage {
+    h: LegacyHeader,
+    h2: LegacyHeader,
+    items: Vec<LegacyHeader>,
+    index: HashMap<String, LegacyHeader>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Header {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    k: Vec<u8>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Message {
+    h: Header,
+    h2: Header,
+    items: Vec<Header>,
+    index: HashMap<String, Header>,
+}
+
+fn legacy_message(k: i32) -> LegacyMessage {
+    LegacyMessage {
+        h: LegacyHeader { k: vec![1] },
+        h2: LegacyHeader { k: vec![2, k] },
+        items: vec![LegacyHeader { k: vec![3] }],
+        index: HashMap::from([("a".to_string(), LegacyHeader { k: vec![4] })]),
+    }
+}
+
+fn main() {
+    let schema = Schema::parse_str(LEGACY).expect("valid avro schema");
+    let mut writer = Writer::new(&schema, Vec::new());
+    writer
+        .append_ser(LegacyRecord {
+            key: vec![0, 1, 255],
+            option: Some(vec![4, 5, 6]),
+        })
+        .expect("legacy record");
+    writer
+        .append_ser(LegacyRecord {
+            key: vec![],
+            option: None,
+        })
+        .expect("legacy record");
+    let legacy = writer.into_inner().expect("legacy file");
+
+    let mut migrated = Vec::new();
+    let count = serde_avro_bytes::migrate::migrate(
+        legacy.as_slice(),
+        &mut migrated,
+        &["key", "option"],
+        Codec::Deflate,
+    )
+    .expect("migrated file");
+    assert_eq!(count, 2);
+    assert!(migrated.len() < legacy.len());
+
+    let reader = Reader::new(migrated.as_slice()).expect("migrated reader");
+    let records = reader
+        .map(|value| apache_avro::from_value::<Record>(&value.expect("value")).expect("record"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        records,
+        vec![
+            Record {
+                key: vec![0, 1, 255],
+                option: Some(vec![4, 5, 6]),
+            },
+            Record {
+                key: vec![],
+                option: None,
+            },
+        ]
+    );
+
+    // every record variant of a union holding the field is rewritten
+    let schema = Schema::parse_str(LEGACY_UNION).expect("valid avro schema");
+    let migrated = serde_avro_bytes::migrate::migrate_schema(&schema, &["payload.key"])
+        .expect("migrated schema");
+    let canonical = migrated.canonical_form();
+    assert_eq!(canonical.matches(r#""type":"bytes""#).count(), 2);
+    assert!(!canonical.contains(r#""items":"int""#));
+
+    // a named record is rewritten in its definition, so is the data of every field using it,
+    // and paths go through arrays and maps of records
+    let schema = Schema::parse_str(LEGACY_SHARED).expect("valid avro schema");
+    let mut writer = Writer::new(&schema, Vec::new());
+    writer.append_ser(legacy_message(5)).expect("legacy record");
+    let legacy = writer.into_inner().expect("legacy file");
+    for fields in [
+        ["h.k", "items.k", "index.k"],
+        ["h2.k", "items[].k", "index[value].k"],
+    ] {
+        let mut migrated = Vec::new();
+        serde_avro_bytes::migrate::migrate(legacy.as_slice(), &mut migrated, &fields, Codec::Null)
+            .expect("migrated file");
+        let value = Reader::new(migrated.as_slice())
+            .expect("migrated reader")
+            .next()
+            .expect("one record")
+            .expect("migrated value");
+        assert_eq!(
+            apache_avro::from_value::<Message>(&value).expect("message"),
+            Message {
+                h: Header { k: vec![1] },
+                h2: Header { k: vec![2, 5] },
+                items: vec![Header { k: vec![3] }],
+                index: HashMap::from([("a".to_string(), Header { k: vec![4] })]),
+            }
+        );
+    }
+
+    // nothing is written unless every record is converted
+    let mut writer = Writer::new(&schema, Vec::new());
+    writer.append_ser(legacy_message(5)).expect("legacy record");
+    writer
+        .append_ser(legacy_message(300))
+        .expect("legacy record");
+    let legacy = writer.into_inner().expect("legacy file");
+    let mut migrated = Vec::new();
+    let err =
+        serde_avro_bytes::migrate::migrate(legacy.as_slice(), &mut migrated, &["h.k"], Codec::Null)
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "field `h2.k` holds 300 at index 1 which is not in 0..=255"
+    );
+    assert!(migrated.is_empty());
+}
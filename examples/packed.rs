@@ -0,0 +1,95 @@
+use apache_avro::{types::Value, AvroSchema};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Document {
+    #[serde(with = "serde_avro_bytes::packed")]
+    embedding: Vec<f32>,
+    #[serde(with = "serde_avro_bytes::packed::option")]
+    ids: Option<Vec<u64>>,
+    #[serde(with = "serde_avro_bytes::packed::delta")]
+    postings: Vec<u64>,
+    #[serde(with = "serde_avro_bytes::packed::delta::option")]
+    timestamps: Option<Vec<i64>>,
+}
+
+impl AvroSchema for Document {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Document",
+            [
+                ("embedding", serde_avro_bytes::packed::schema()),
+                ("ids", serde_avro_bytes::packed::option::schema()),
+                ("postings", serde_avro_bytes::packed::delta::schema()),
+                (
+                    "timestamps",
+                    serde_avro_bytes::packed::delta::option::schema(),
+                ),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let document = Document {
+        embedding: vec![0.25, -1.5, 3.0],
+        ids: Some(vec![1, u64::MAX]),
+        postings: vec![1000, 1001, 1001, 1300],
+        timestamps: Some(vec![i64::MIN, -5, 0, i64::MAX]),
+    };
+
+    let value = apache_avro::to_value(&document).expect("avro value");
+    let Value::Record(fields) = &value else {
+        panic!("record expected")
+    };
+    assert_eq!(
+        fields[0].1,
+        Value::Bytes(
+            [0.25f32, -1.5, 3.0]
+                .iter()
+                .flat_map(|x| x.to_le_bytes())
+                .collect()
+        )
+    );
+
+    // 1000 as a two bytes LEB128 integer, then deltas of 1, 0 and 299
+    assert_eq!(
+        fields[2].1,
+        Value::Bytes(vec![0xe8, 0x07, 0x01, 0x00, 0xab, 0x02])
+    );
+
+    let schema = Document::get_schema();
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Document>(&decoded).expect("document"),
+        document
+    );
+
+    // 7 bytes cannot hold whole `f32` elements
+    let truncated = Value::Record(vec![
+        ("embedding".to_string(), Value::Bytes(vec![0; 7])),
+        ("ids".to_string(), Value::Union(0, Box::new(Value::Null))),
+        ("postings".to_string(), Value::Bytes(vec![])),
+        (
+            "timestamps".to_string(),
+            Value::Union(0, Box::new(Value::Null)),
+        ),
+    ]);
+    let err = apache_avro::from_value::<Document>(&truncated).expect_err("invalid length");
+    assert!(err
+        .to_string()
+        .contains("7 bytes are not a multiple of the 4-byte element size"));
+
+    // delta encoding needs sorted values
+    let unsorted = Document {
+        postings: vec![3, 2],
+        ..document
+    };
+    let err = apache_avro::to_value(&unsorted).expect_err("unsorted postings");
+    assert!(err
+        .to_string()
+        .contains("value at index 1 is smaller than the previous one"));
+}
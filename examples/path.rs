@@ -0,0 +1,50 @@
+use std::{
+    ffi::OsString,
+    os::unix::ffi::{OsStrExt, OsStringExt},
+    path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::path")]
+    path: PathBuf,
+    #[serde(with = "serde_avro_bytes::path::option")]
+    name: Option<OsString>,
+    #[serde(with = "serde_avro_bytes::path::list")]
+    paths: Vec<PathBuf>,
+    #[serde(with = "serde_avro_bytes::path::list::option")]
+    names: Option<Vec<OsString>>,
+}
+
+fn main() {
+    let schema = serde_avro_bytes::schema::record(
+        "Record",
+        [
+            ("path", serde_avro_bytes::path::schema()),
+            ("name", serde_avro_bytes::path::option::schema()),
+            ("paths", serde_avro_bytes::path::list::schema()),
+            ("names", serde_avro_bytes::path::list::option::schema()),
+        ],
+    )
+    .expect("valid record schema");
+
+    // not valid UTF-8, rejected by the serde implementation of `PathBuf`
+    let invalid = OsString::from_vec(b"/tmp/\xff\xfe.log".to_vec());
+    let record = Record {
+        path: PathBuf::from(&invalid),
+        name: Some(invalid.clone()),
+        paths: vec![PathBuf::from("/etc/hosts"), PathBuf::from(&invalid)],
+        names: Some(vec![invalid.clone()]),
+    };
+
+    let value = apache_avro::to_value(&record).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    let decoded = apache_avro::from_value::<Record>(&decoded).expect("record");
+
+    assert_eq!(decoded, record);
+    assert_eq!(decoded.path.as_os_str().as_bytes(), invalid.as_bytes());
+}
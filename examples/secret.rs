@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use apache_avro::AvroSchema;
+use secrecy::{ExposeSecret, SecretVec};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+#[derive(Serialize, Deserialize)]
+struct Credentials {
+    #[serde(with = "serde_avro_bytes::secret::secrecy")]
+    key: SecretVec<u8>,
+    #[serde(with = "serde_avro_bytes::secret::secrecy::option")]
+    previous_key: Option<SecretVec<u8>>,
+    #[serde(with = "serde_avro_bytes::secret::zeroizing")]
+    token: Zeroizing<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::secret::zeroizing::list")]
+    recovery_codes: Vec<Zeroizing<Vec<u8>>>,
+    #[serde(with = "serde_avro_bytes::secret::zeroizing::btreemap")]
+    keyring: BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>,
+}
+
+impl AvroSchema for Credentials {
+    fn get_schema() -> apache_avro::Schema {
+        serde_avro_bytes::schema::record(
+            "Credentials",
+            [
+                ("key", serde_avro_bytes::secret::secrecy::schema()),
+                (
+                    "previous_key",
+                    serde_avro_bytes::secret::secrecy::option::schema(),
+                ),
+                ("token", serde_avro_bytes::secret::zeroizing::schema()),
+                (
+                    "recovery_codes",
+                    serde_avro_bytes::secret::zeroizing::list::schema(),
+                ),
+                (
+                    "keyring",
+                    serde_avro_bytes::secret::zeroizing::btreemap::schema(),
+                ),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let credentials = Credentials {
+        key: SecretVec::new(vec![0xde, 0xad, 0xbe, 0xef]),
+        previous_key: None,
+        token: Zeroizing::new(b"token".to_vec()),
+        recovery_codes: vec![Zeroizing::new(b"1234".to_vec())],
+        keyring: BTreeMap::from([(b"kid-1".to_vec(), Zeroizing::new(vec![0x42; 32]))]),
+    };
+
+    let schema = Credentials::get_schema();
+    let value = apache_avro::to_value(&credentials).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    let decoded = apache_avro::from_value::<Credentials>(&decoded).expect("credentials");
+
+    assert_eq!(decoded.key.expose_secret(), credentials.key.expose_secret());
+    assert!(decoded.previous_key.is_none());
+    assert_eq!(decoded.token, credentials.token);
+    assert_eq!(decoded.recovery_codes, credentials.recovery_codes);
+    assert_eq!(decoded.keyring, credentials.keyring);
+}
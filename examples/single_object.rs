@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+use apache_avro::{AvroSchema, GenericSingleObjectWriter, Schema};
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::single_object::{
+    decode_single_object, encode_single_object, SchemaTable, MARKER,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::btreemap")]
+    map: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl AvroSchema for Record {
+    fn get_schema() -> Schema {
+        serde_avro_bytes::schema::record(
+            "Record",
+            [
+                ("key", serde_avro_bytes::bytes::schema()),
+                ("map", serde_avro_bytes::btreemap::schema()),
+            ],
+        )
+        .expect("valid record schema")
+    }
+}
+
+fn main() {
+    let mut table = SchemaTable::new();
+    table.insert_schema_of::<Record>();
+
+    let record = Record {
+        key: vec![0, 1, 3],
+        map: BTreeMap::from([(vec![1, 5, 6], vec![7, 8, 9])]),
+    };
+    let schema = Record::get_schema();
+    let message = encode_single_object(&schema, &record).expect("encoded record");
+    assert_eq!(message[..2], MARKER);
+
+    // same header and body as the apache-avro single object writer
+    let mut expected = vec![];
+    GenericSingleObjectWriter::new_with_capacity(&schema, 1024)
+        .expect("single object writer")
+        .write_value(
+            apache_avro::to_value(&record).expect("value"),
+            &mut expected,
+        )
+        .expect("single object");
+    assert_eq!(message, expected);
+
+    let decoded = decode_single_object::<Record>(&table, &message).expect("decoded record");
+    assert_eq!(decoded, record);
+
+    assert!(decode_single_object::<Record>(&SchemaTable::new(), &message).is_err());
+}
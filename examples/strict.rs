@@ -0,0 +1,131 @@
+use std::io::Cursor;
+
+use apache_avro::Schema;
+use serde::{Deserialize, Serialize};
+
+static LEGACY: &str = r#"{
+    "name": "Record",
+    "type": "record",
+    "fields": [
+        {
+            "name": "key",
+            "type": {
+                "type": "array",
+                "items": "int"
+            }
+        },
+        {
+            "name": "option",
+            "type": [
+                "null",
+                {
+                    "type": "array",
+                    "items": "int"
+                }
+            ]
+        }
+    ]
+}"#;
+
+/// Record written before byte fields were annotated, the producer did not check the range.
+#[derive(Serialize)]
+struct LegacyRecord {
+    key: Vec<i32>,
+    option: Option<Vec<i32>>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes::strict")]
+    key: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::bytes::strict::option")]
+    option: Option<Vec<u8>>,
+}
+
+#[derive(Deserialize)]
+struct MigratedRecord {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    #[allow(unused)]
+    #[serde(with = "serde_avro_bytes::bytes::option")]
+    option: Option<Vec<u8>>,
+}
+
+fn decode(schema: &Schema, record: LegacyRecord) -> Result<Record, apache_avro::Error> {
+    let value = apache_avro::to_value(record).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(schema, value).expect("encoded value");
+    let value = apache_avro::from_avro_datum(schema, &mut Cursor::new(encoded), None)
+        .expect("decoded value");
+    apache_avro::from_value::<Record>(&value)
+}
+
+fn main() {
+    let schema = Schema::parse_str(LEGACY).expect("valid avro schema");
+
+    let record = decode(
+        &schema,
+        LegacyRecord {
+            key: vec![0, 1, 255],
+            option: Some(vec![4, 5]),
+        },
+    )
+    .expect("record");
+    assert_eq!(
+        record,
+        Record {
+            key: vec![0, 1, 255],
+            option: Some(vec![4, 5]),
+        }
+    );
+
+    // the index and value of the first element not fitting in a `u8` are reported
+    let err = decode(
+        &schema,
+        LegacyRecord {
+            key: vec![1, 300, -1],
+            option: None,
+        },
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid byte at index 1: 300 is not in 0..=255"));
+
+    let err = decode(
+        &schema,
+        LegacyRecord {
+            key: vec![],
+            option: Some(vec![7, -1]),
+        },
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid byte at index 1: -1 is not in 0..=255"));
+
+    // once migrated, the data is read with `bytes` and no longer with `strict`
+    let mut migrated = Vec::new();
+    let mut writer = apache_avro::Writer::new(&schema, Vec::new());
+    writer
+        .append_ser(LegacyRecord {
+            key: vec![0, 1],
+            option: None,
+        })
+        .expect("legacy record");
+    let legacy = writer.into_inner().expect("legacy file");
+    serde_avro_bytes::migrate::migrate(
+        legacy.as_slice(),
+        &mut migrated,
+        &["key", "option"],
+        apache_avro::Codec::Null,
+    )
+    .expect("migrated file");
+    let value = apache_avro::Reader::new(migrated.as_slice())
+        .expect("migrated reader")
+        .next()
+        .expect("one record")
+        .expect("migrated value");
+    assert!(apache_avro::from_value::<Record>(&value).is_err());
+    let record = apache_avro::from_value::<MigratedRecord>(&value).expect("migrated record");
+    assert_eq!(record.key, vec![0, 1]);
+}
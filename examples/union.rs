@@ -0,0 +1,97 @@
+use apache_avro::{types::Value, Schema};
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::union::{Branch, UnionLayout};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+enum Payload {
+    Raw(#[serde(with = "serde_avro_bytes::bytes")] Vec<u8>),
+    Keyed(
+        #[serde(with = "serde_avro_bytes::bytes")] Vec<u8>,
+        #[serde(with = "serde_avro_bytes::bytes")] Vec<u8>,
+    ),
+    Empty,
+    Signature(#[serde(with = "serde_avro_bytes::bytes")] Vec<u8>),
+}
+
+fn main() {
+    let layout = UnionLayout::new([
+        ("Raw", Branch::Newtype(serde_avro_bytes::bytes::schema())),
+        (
+            "Keyed",
+            Branch::Tuple(vec![
+                serde_avro_bytes::bytes::schema(),
+                serde_avro_bytes::bytes::schema(),
+            ]),
+        ),
+        ("Empty", Branch::Unit),
+        // a second `bytes` variant is kept apart in a record
+        (
+            "Signature",
+            Branch::Wrapped(serde_avro_bytes::bytes::schema()),
+        ),
+    ]);
+    let schema = layout.schema().expect("valid union schema");
+    let expected = Schema::parse_str(
+        r#"[
+            "bytes",
+            {
+                "type": "record",
+                "name": "Keyed",
+                "fields": [
+                    {"name": "_0", "type": "bytes"},
+                    {"name": "_1", "type": "bytes"}
+                ]
+            },
+            "null",
+            {
+                "type": "record",
+                "name": "Signature",
+                "fields": [
+                    {"name": "_0", "type": "bytes"}
+                ]
+            }
+        ]"#,
+    )
+    .expect("valid avro schema");
+    assert_eq!(schema.canonical_form(), expected.canonical_form());
+
+    for payload in [
+        Payload::Raw(vec![0, 1, 3]),
+        Payload::Keyed(vec![4, 5], vec![6]),
+        Payload::Empty,
+        Payload::Signature(vec![7, 8]),
+    ] {
+        let value = apache_avro::to_value(&payload).expect("avro value");
+        let value = layout.to_union(value).expect("union value");
+        if let Payload::Raw(bytes) = &payload {
+            assert_eq!(
+                value,
+                Value::Union(0, Box::new(Value::Bytes(bytes.clone())))
+            );
+        }
+
+        let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+        let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+            .expect("decoded value");
+        let decoded = layout.from_union(decoded).expect("enum value");
+        assert_eq!(
+            apache_avro::from_value::<Payload>(&decoded).expect("payload"),
+            payload
+        );
+    }
+
+    // a union cannot hold two `bytes` branches
+    let layout = UnionLayout::new([
+        ("Raw", Branch::Newtype(serde_avro_bytes::bytes::schema())),
+        (
+            "Signature",
+            Branch::Newtype(serde_avro_bytes::bytes::schema()),
+        ),
+    ]);
+    let err = layout.schema().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "variants `Raw` and `Signature` have the same Avro type, \
+         encode one of them with `Branch::Wrapped`"
+    );
+}
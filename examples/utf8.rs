@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use apache_avro::Schema;
+use serde::{Deserialize, Serialize};
+
+/// Written by a producer sending raw bytes.
+#[derive(Serialize)]
+struct Raw {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    name: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::list")]
+    tags: Vec<Vec<u8>>,
+    #[serde(with = "serde_avro_bytes::hashmap")]
+    labels: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Strict {
+    #[serde(with = "serde_avro_bytes::utf8::strict")]
+    #[allow(unused)]
+    name: String,
+    #[serde(with = "serde_avro_bytes::utf8::strict::list")]
+    #[allow(unused)]
+    tags: Vec<String>,
+    #[serde(with = "serde_avro_bytes::utf8::strict::hashmap")]
+    #[allow(unused)]
+    labels: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Lossy {
+    #[serde(with = "serde_avro_bytes::utf8::lossy")]
+    name: String,
+    #[serde(with = "serde_avro_bytes::utf8::lossy::list")]
+    tags: Vec<String>,
+    #[serde(with = "serde_avro_bytes::utf8::lossy::hashmap")]
+    labels: HashMap<String, String>,
+}
+
+fn schema() -> Schema {
+    serde_avro_bytes::schema::record(
+        "Record",
+        [
+            ("name", serde_avro_bytes::utf8::strict::schema()),
+            ("tags", serde_avro_bytes::utf8::strict::list::schema()),
+            ("labels", serde_avro_bytes::utf8::strict::hashmap::schema()),
+        ],
+    )
+    .expect("valid record schema")
+}
+
+fn main() {
+    let schema = schema();
+    let raw = Raw {
+        name: b"caf\xc3\xa9".to_vec(),
+        tags: vec![b"ok".to_vec(), b"br\xffken".to_vec()],
+        labels: HashMap::from([(b"env".to_vec(), b"prod".to_vec())]),
+    };
+    let value = apache_avro::to_value(&raw).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+
+    let err = apache_avro::from_value::<Strict>(&decoded).expect_err("invalid tag");
+    let err = err.to_string();
+    assert!(err.contains("at index 1") && err.contains("invalid UTF-8 at byte offset 2"));
+
+    let lossy = apache_avro::from_value::<Lossy>(&decoded).expect("lossy record");
+    assert_eq!(
+        lossy,
+        Lossy {
+            name: "café".to_string(),
+            tags: vec!["ok".to_string(), "br\u{FFFD}ken".to_string()],
+            labels: HashMap::from([("env".to_string(), "prod".to_string())]),
+        }
+    );
+
+    // valid strings round-trip unchanged
+    let value = apache_avro::to_value(&lossy).expect("avro value");
+    let encoded = apache_avro::to_avro_datum(&schema, value).expect("encoded value");
+    let decoded = apache_avro::from_avro_datum(&schema, &mut encoded.as_slice(), None)
+        .expect("decoded value");
+    assert_eq!(
+        apache_avro::from_value::<Lossy>(&decoded).expect("lossy record"),
+        lossy
+    );
+
+    // map errors tell the index and the key of the entry
+    let raw = Raw {
+        name: b"ok".to_vec(),
+        tags: vec![],
+        labels: HashMap::from([(b"env".to_vec(), b"p\xffod".to_vec())]),
+    };
+    let value = apache_avro::to_value(&raw).expect("avro value");
+    let err = apache_avro::from_value::<Strict>(&value).expect_err("invalid label");
+    assert!(err
+        .to_string()
+        .contains("at index 0: at key 0x656e76: invalid UTF-8 at byte offset 1"));
+}
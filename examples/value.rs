@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use apache_avro::types::Value;
+use serde::{Deserialize, Serialize};
+use serde_avro_bytes::value::{map_to_pairs, pairs_to_map, pretty};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    #[serde(with = "serde_avro_bytes::bytes")]
+    key: Vec<u8>,
+    #[serde(with = "serde_avro_bytes::btreemap")]
+    headers: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+fn main() {
+    let record = Record {
+        key: vec![0xff, 0x00],
+        headers: BTreeMap::from([
+            (b"content-type".to_vec(), b"text/plain".to_vec()),
+            (b"trace".to_vec(), vec![0x01, 0x02]),
+        ]),
+    };
+    let value = apache_avro::to_value(&record).expect("avro value");
+
+    // the entries of a map field are borrowed from its array of `Pair` records
+    let Value::Record(fields) = &value else {
+        panic!("record value")
+    };
+    let entries = pairs_to_map(&fields[1].1).expect("array of pairs");
+    assert_eq!(
+        entries,
+        vec![
+            (&b"content-type"[..], &b"text/plain"[..]),
+            (&b"trace"[..], &[0x01, 0x02][..]),
+        ]
+    );
+    assert_eq!(pairs_to_map(&fields[0].1), None);
+
+    // and the array is rebuilt from the entries
+    let rebuilt = Value::Record(vec![
+        fields[0].clone(),
+        ("headers".to_string(), map_to_pairs(entries)),
+    ]);
+    assert_eq!(rebuilt, value);
+    assert_eq!(
+        apache_avro::from_value::<Record>(&rebuilt).expect("record"),
+        record
+    );
+
+    // maps are shown with their bytes as strings when printable and in hexadecimal otherwise
+    assert_eq!(
+        pretty(&value).to_string(),
+        r#"{
+  key: 0xff00
+  headers: {
+    b"content-type" => b"text/plain"
+    b"trace" => 0x0102
+  }
+}"#
+    );
+}
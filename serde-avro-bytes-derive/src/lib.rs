@@ -0,0 +1,323 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::{
+    parse::Parser, parse_macro_input, parse_quote, punctuated::Punctuated, Attribute, Field,
+    Fields, GenericArgument, Item, LitStr, Path, PathArguments, Token, Type,
+};
+
+/// Annotates every bytes-like field of a struct or an enum with the matching
+/// `serde_avro_bytes` module.
+///
+/// Must be placed above `#[derive(Serialize, Deserialize)]`, which is a compile
+/// error otherwise as the derive would not see the annotations. Fields already
+/// carrying a `with`, `serialize_with` or `deserialize_with` serde attribute are
+/// left untouched, and a field can be excluded with `#[avro_bytes(skip)]`.
+///
+/// Recognized types are `Vec<u8>` and `BString`, alone or in a `Vec`, a
+/// `HashMap` or a `BTreeMap` (same key and value type), and pairs of `Vec<u8>`,
+/// alone or in a `Vec`, all optionally wrapped in an `Option`. Any other type
+/// holding bytes, such as `[u8; 16]`, `Box<[u8]>` or `HashMap<String, Vec<u8>>`,
+/// is a compile error until the field is skipped or annotated by hand.
+///
+/// A type alias cannot be seen through: `#[avro_bytes(as = "Vec<u8>")]` names
+/// the type it stands for, and fails to compile when that type is not recognized.
+///
+/// The path of the crate defaults to `serde_avro_bytes` and can be changed
+/// with `#[avro_bytes(crate = "path")]`.
+#[proc_macro_attribute]
+pub fn avro_bytes(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut crate_path = "serde_avro_bytes".to_string();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            crate_path = meta.value()?.parse::<LitStr>()?.value();
+            Ok(())
+        } else {
+            Err(meta.error("unsupported avro_bytes argument"))
+        }
+    });
+    if let Err(err) = parser.parse(args) {
+        return err.to_compile_error().into();
+    }
+
+    let mut item = parse_macro_input!(input as Item);
+    let result = match &mut item {
+        Item::Struct(item) => combine(
+            [
+                require_serde_derive(&item.attrs),
+                annotate(&mut item.fields, &crate_path),
+            ]
+            .into_iter(),
+        ),
+        Item::Enum(item) => combine(
+            std::iter::once(require_serde_derive(&item.attrs)).chain(
+                item.variants
+                    .iter_mut()
+                    .map(|variant| annotate(&mut variant.fields, &crate_path)),
+            ),
+        ),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "`#[avro_bytes]` applies to structs and enums",
+        )),
+    };
+
+    match result {
+        Ok(()) => item.into_token_stream().into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Refuses an item without a `Serialize` or `Deserialize` derive below the attribute,
+/// which happens when the attribute is placed below the derive: it has already run.
+fn require_serde_derive(attrs: &[Attribute]) -> syn::Result<()> {
+    let derived = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .filter_map(|path| path.segments.last().map(|segment| segment.ident.clone()))
+        .any(|ident| ident == "Serialize" || ident == "Deserialize");
+    if derived {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`#[avro_bytes]` must be placed above `#[derive(Serialize, Deserialize)]`",
+        ))
+    }
+}
+
+/// Annotates the fields, reporting every field which cannot be annotated.
+fn annotate(fields: &mut Fields, crate_path: &str) -> syn::Result<()> {
+    combine(
+        fields
+            .iter_mut()
+            .map(|field| annotate_field(field, crate_path)),
+    )
+}
+
+/// Gathers the errors of all the results into a single one.
+fn combine(results: impl Iterator<Item = syn::Result<()>>) -> syn::Result<()> {
+    results
+        .filter_map(Result::err)
+        .reduce(|mut errors, err| {
+            errors.combine(err);
+            errors
+        })
+        .map_or(Ok(()), Err)
+}
+
+fn annotate_field(field: &mut Field, crate_path: &str) -> syn::Result<()> {
+    let args = take_field_args(&mut field.attrs)?;
+    if args.skip || has_serde_with(&field.attrs) {
+        return Ok(());
+    }
+    let module = match &args.alias_of {
+        Some(ty) => module(ty).ok_or_else(|| {
+            syn::Error::new_spanned(
+                ty,
+                "`#[avro_bytes(as = ...)]` names a type without serde_avro_bytes module",
+            )
+        })?,
+        None => match module(&field.ty) {
+            Some(module) => module,
+            None if holds_bytes(&field.ty) => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "no serde_avro_bytes module handles this type, annotate the field \
+                     with `#[serde(with = ...)]` or exclude it with `#[avro_bytes(skip)]`",
+                ))
+            }
+            None => return Ok(()),
+        },
+    };
+    let with = LitStr::new(
+        &format!("{crate_path}::{module}"),
+        proc_macro2::Span::call_site(),
+    );
+    field.attrs.push(parse_quote!(#[serde(with = #with)]));
+    Ok(())
+}
+
+#[derive(Default)]
+struct FieldArgs {
+    skip: bool,
+    /// Type a type alias stands for, from `#[avro_bytes(as = "...")]`.
+    alias_of: Option<Type>,
+}
+
+/// Removes the `#[avro_bytes(...)]` field attributes, returning their arguments.
+fn take_field_args(attrs: &mut Vec<Attribute>) -> syn::Result<FieldArgs> {
+    let mut args = FieldArgs::default();
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("avro_bytes") {
+            return true;
+        }
+        let parsed = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                args.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("as") {
+                args.alias_of = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported avro_bytes field argument"))
+            }
+        });
+        if let Err(err) = parsed {
+            result = Err(err);
+        }
+        false
+    });
+    result.map(|()| args)
+}
+
+fn has_serde_with(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .flat_map(|list| list.tokens.clone())
+        .any(|token| match token {
+            TokenTree::Ident(ident) => {
+                ident == "with" || ident == "serialize_with" || ident == "deserialize_with"
+            }
+            _ => false,
+        })
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Leaf {
+    Bytes,
+    BString,
+}
+
+/// Module path, relative to the crate, handling a field of type `ty`.
+fn module(ty: &Type) -> Option<String> {
+    if let Some([inner]) = generic_args(ty, "Option").as_deref() {
+        return base_module(inner).map(|module| format!("{module}::option"));
+    }
+    base_module(ty)
+}
+
+fn base_module(ty: &Type) -> Option<String> {
+    if is_bytes_tuple(ty) {
+        return Some("tuple".to_string());
+    }
+    if let Some([item]) = generic_args(ty, "Vec").as_deref() {
+        if is_bytes_tuple(item) {
+            return Some("tuple::list".to_string());
+        }
+    }
+
+    let (leaf, shape) = if let Some(leaf) = leaf(ty) {
+        (leaf, None)
+    } else if let Some([item]) = generic_args(ty, "Vec").as_deref() {
+        (leaf(item)?, Some("list"))
+    } else if let Some([key, value]) = generic_args(ty, "HashMap").as_deref() {
+        (same_leaf(key, value)?, Some("hashmap"))
+    } else if let Some([key, value]) = generic_args(ty, "BTreeMap").as_deref() {
+        (same_leaf(key, value)?, Some("btreemap"))
+    } else {
+        return None;
+    };
+
+    let module = match (leaf, shape) {
+        (Leaf::Bytes, None) => "bytes".to_string(),
+        (Leaf::Bytes, Some(shape)) => shape.to_string(),
+        (Leaf::BString, None) => "extra::bstr".to_string(),
+        (Leaf::BString, Some(shape)) => format!("extra::bstr::{shape}"),
+    };
+    Some(module)
+}
+
+fn is_bytes_tuple(ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(tuple) => {
+            tuple.elems.len() == 2
+                && tuple
+                    .elems
+                    .iter()
+                    .all(|elem| leaf(elem) == Some(Leaf::Bytes))
+        }
+        _ => false,
+    }
+}
+
+fn same_leaf(key: &Type, value: &Type) -> Option<Leaf> {
+    let key = leaf(key)?;
+    (leaf(value)? == key).then_some(key)
+}
+
+fn leaf(ty: &Type) -> Option<Leaf> {
+    if let Some([item]) = generic_args(ty, "Vec").as_deref() {
+        return is_ident(item, "u8").then_some(Leaf::Bytes);
+    }
+    is_ident(ty, "BString").then_some(Leaf::BString)
+}
+
+/// Tells whether `ty` holds bytes anywhere: `u8` arrays or slices, `Vec<u8>` or `BString`.
+fn holds_bytes(ty: &Type) -> bool {
+    match ty {
+        Type::Array(array) => is_ident(&array.elem, "u8") || holds_bytes(&array.elem),
+        Type::Slice(slice) => is_ident(&slice.elem, "u8") || holds_bytes(&slice.elem),
+        Type::Reference(reference) => holds_bytes(&reference.elem),
+        Type::Paren(paren) => holds_bytes(&paren.elem),
+        Type::Group(group) => holds_bytes(&group.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().any(holds_bytes),
+        Type::Path(path) => {
+            leaf(ty).is_some()
+                || path
+                    .path
+                    .segments
+                    .iter()
+                    .filter_map(|segment| match &segment.arguments {
+                        PathArguments::AngleBracketed(arguments) => Some(&arguments.args),
+                        _ => None,
+                    })
+                    .flatten()
+                    .any(
+                        |argument| matches!(argument, GenericArgument::Type(ty) if holds_bytes(ty)),
+                    )
+        }
+        _ => false,
+    }
+}
+
+fn is_ident(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name && segment.arguments.is_none()),
+        _ => false,
+    }
+}
+
+/// Type arguments of `ty` when its last path segment is `name`.
+fn generic_args<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if path.qself.is_some() || segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    arguments
+        .args
+        .iter()
+        .map(|argument| match argument {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
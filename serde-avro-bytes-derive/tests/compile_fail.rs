@@ -0,0 +1,5 @@
+#[test]
+fn unsupported_bytes_fields() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
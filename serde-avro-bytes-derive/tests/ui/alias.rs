@@ -0,0 +1,13 @@
+use serde::Serialize;
+use serde_avro_bytes_derive::avro_bytes;
+
+type Key = Box<[u8]>;
+
+#[avro_bytes]
+#[derive(Serialize)]
+struct Record {
+    #[avro_bytes(as = "Box<[u8]>")]
+    key: Key,
+}
+
+fn main() {}
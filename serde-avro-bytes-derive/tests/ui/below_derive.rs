@@ -0,0 +1,10 @@
+use serde::Serialize;
+use serde_avro_bytes_derive::avro_bytes;
+
+#[derive(Serialize)]
+#[avro_bytes]
+struct Record {
+    key: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use serde_avro_bytes_derive::avro_bytes;
+
+#[avro_bytes]
+#[derive(Serialize)]
+struct Record {
+    #[avro_bytes(rename = "other")]
+    key: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+use serde_avro_bytes_derive::avro_bytes;
+
+#[avro_bytes]
+#[derive(Serialize)]
+struct Record {
+    id: [u8; 16],
+    payload: Box<[u8]>,
+    headers: std::collections::HashMap<String, Vec<u8>>,
+    count: i32,
+}
+
+fn main() {}
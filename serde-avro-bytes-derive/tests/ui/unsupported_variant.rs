@@ -0,0 +1,12 @@
+use serde::Serialize;
+use serde_avro_bytes_derive::avro_bytes;
+
+#[avro_bytes]
+#[derive(Serialize)]
+enum Payload {
+    Raw(Vec<u8>),
+    Digest([u8; 32]),
+    Chunks(Vec<Box<[u8]>>),
+}
+
+fn main() {}
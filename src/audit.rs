@@ -0,0 +1,486 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+};
+
+use apache_avro::{schema::RecordSchema, Schema};
+use serde::{ser, Serialize};
+
+#[derive(Debug)]
+pub struct AuditError(String);
+
+impl Display for AuditError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for AuditError {}
+
+impl ser::Error for AuditError {
+    fn custom<T: Display>(msg: T) -> Self {
+        AuditError(msg.to_string())
+    }
+}
+
+/// Paths of byte fields encoded as `array<int>` because they lack a `with`
+/// annotation, found in both `value` and the `schema` it is written with.
+///
+/// In `value`, these are the non-empty sequences of `u8` serialized element by
+/// element rather than through `serialize_bytes`. Empty ones cannot be told
+/// apart from other empty sequences and are found through the `schema` only,
+/// see [`audit_schema`].
+///
+/// Paths join struct fields with `.` and mark sequence items with `[]`, map
+/// keys with `[key]` and map values with `[value]`. The payload of an enum
+/// variant is named after the variant, in the schema as well when it is the
+/// `type`/`value` record of `apache_avro::to_value` or a record of
+/// `union::UnionLayout` named after the variant.
+pub fn audit<T: Serialize + ?Sized>(value: &T, schema: &Schema) -> Result<Vec<String>, AuditError> {
+    let mut probe = Probe::default();
+    value.serialize(&mut probe)?;
+    let mut findings = probe.findings;
+    findings.extend(audit_schema(schema));
+    Ok(findings.into_iter().collect())
+}
+
+/// Paths of `schema` declared as `array<int>`, the shape of un-annotated byte fields.
+pub fn audit_schema(schema: &Schema) -> Vec<String> {
+    let mut findings = BTreeSet::new();
+    walk_schema(schema, &mut vec![], &mut findings);
+    findings.into_iter().collect()
+}
+
+fn walk_schema(schema: &Schema, path: &mut Vec<String>, findings: &mut BTreeSet<String>) {
+    match schema {
+        Schema::Array(items) if **items == Schema::Int => {
+            findings.insert(render(path));
+        }
+        Schema::Array(items) => {
+            path.push("[]".to_string());
+            walk_schema(items, path, findings);
+            path.pop();
+        }
+        Schema::Map(values) => {
+            path.push("[value]".to_string());
+            walk_schema(values, path, findings);
+            path.pop();
+        }
+        Schema::Union(union) => {
+            for variant in union.variants() {
+                match variant {
+                    Schema::Record(record) if is_variant_record(record) => {
+                        path.push(record.name.name.clone());
+                        walk_variant_fields(record, path, findings);
+                        path.pop();
+                    }
+                    variant => walk_schema(variant, path, findings),
+                }
+            }
+        }
+        Schema::Record(record) => {
+            if let Some(variants) = enum_variants(record) {
+                for (symbol, payload) in variants {
+                    path.push(symbol.clone());
+                    walk_schema(payload, path, findings);
+                    path.pop();
+                }
+                return;
+            }
+            for field in &record.fields {
+                path.push(field.name.clone());
+                walk_schema(&field.schema, path, findings);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Symbols and payload schemas of the `type`/`value` record `apache_avro::to_value`
+/// makes of an enum variant, the `value` union having a branch per symbol.
+fn enum_variants(record: &RecordSchema) -> Option<impl Iterator<Item = (&String, &Schema)>> {
+    let field = |name: &str| {
+        record
+            .lookup
+            .get(name)
+            .map(|&index| &record.fields[index].schema)
+    };
+    match (record.fields.len(), field("type")?, field("value")?) {
+        (2, Schema::Enum(symbols), Schema::Union(payloads))
+            if symbols.symbols.len() == payloads.variants().len() =>
+        {
+            Some(symbols.symbols.iter().zip(payloads.variants()))
+        }
+        _ => None,
+    }
+}
+
+/// Tells whether `record` is the branch `union::UnionLayout` makes of a wrapped
+/// newtype or a tuple variant, with fields named `_0`, `_1`...
+fn is_variant_record(record: &RecordSchema) -> bool {
+    !record.fields.is_empty()
+        && record
+            .fields
+            .iter()
+            .enumerate()
+            .all(|(index, field)| field.name == format!("_{index}"))
+}
+
+/// Walks the fields of a variant record as the probe does: the field of a newtype
+/// variant is the payload itself, the fields of a tuple variant are its items.
+fn walk_variant_fields(
+    record: &RecordSchema,
+    path: &mut Vec<String>,
+    findings: &mut BTreeSet<String>,
+) {
+    if let [field] = record.fields.as_slice() {
+        walk_schema(&field.schema, path, findings);
+        return;
+    }
+    path.push("[]".to_string());
+    for field in &record.fields {
+        walk_schema(&field.schema, path, findings);
+    }
+    path.pop();
+}
+
+pub(crate) fn render(path: &[String]) -> String {
+    let mut rendered = String::new();
+    for segment in path {
+        if !rendered.is_empty() && !segment.starts_with('[') {
+            rendered.push('.');
+        }
+        rendered.push_str(segment);
+    }
+    if rendered.is_empty() {
+        rendered.push_str("(root)");
+    }
+    rendered
+}
+
+#[derive(Default)]
+struct Probe {
+    path: Vec<String>,
+    findings: BTreeSet<String>,
+}
+
+impl Probe {
+    fn nested<T: Serialize + ?Sized>(
+        &mut self,
+        segment: &str,
+        value: &T,
+    ) -> Result<Emitted, AuditError> {
+        self.path.push(segment.to_string());
+        let emitted = value.serialize(&mut *self);
+        self.path.pop();
+        emitted
+    }
+}
+
+/// What a value was serialized as, as far as the probe is concerned.
+#[derive(PartialEq)]
+enum Emitted {
+    U8,
+    Other,
+}
+
+struct Compound<'a> {
+    probe: &'a mut Probe,
+    len: usize,
+    all_u8: bool,
+    variant: bool,
+}
+
+impl<'a> Compound<'a> {
+    fn new(probe: &'a mut Probe) -> Self {
+        Compound {
+            probe,
+            len: 0,
+            all_u8: true,
+            variant: false,
+        }
+    }
+
+    fn variant(probe: &'a mut Probe, variant: &str) -> Self {
+        probe.path.push(variant.to_string());
+        Compound {
+            variant: true,
+            ..Compound::new(probe)
+        }
+    }
+
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), AuditError> {
+        let emitted = self.probe.nested("[]", value)?;
+        self.len += 1;
+        self.all_u8 &= emitted == Emitted::U8;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        if self.len > 0 && self.all_u8 {
+            self.probe.findings.insert(render(&self.probe.path));
+        }
+        if self.variant {
+            self.probe.path.pop();
+        }
+        Ok(Emitted::Other)
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Probe {
+    type Ok = Emitted;
+    type Error = AuditError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, _: bool) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<Emitted, AuditError> {
+        Ok(Emitted::U8)
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_char(self, _: char) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_str(self, _: &str) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_none(self) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Emitted, AuditError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<Emitted, AuditError> {
+        Ok(Emitted::Other)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Emitted, AuditError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Emitted, AuditError> {
+        self.nested(variant, value)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::variant(self, variant))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, AuditError> {
+        Ok(Compound::variant(self, variant))
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), AuditError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), AuditError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), AuditError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), AuditError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), AuditError> {
+        self.all_u8 = false;
+        self.probe.nested("[key]", key).map(drop)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), AuditError> {
+        self.probe.nested("[value]", value).map(drop)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), AuditError> {
+        self.all_u8 = false;
+        self.probe.nested(key, value).map(drop)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = Emitted;
+    type Error = AuditError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), AuditError> {
+        self.all_u8 = false;
+        self.probe.nested(key, value).map(drop)
+    }
+
+    fn end(self) -> Result<Emitted, AuditError> {
+        Compound::end(self)
+    }
+}
@@ -0,0 +1,65 @@
+//! Bit sets stored as their length in bits, as an unsigned LEB128 integer, followed by
+//! the bits packed least significant first.
+
+use crate::avro_bytes::{error::Error as AvroBytesError, varint};
+
+/// Encodes `len` bits whose packed bytes are `packed`, clearing the bits past the length.
+pub(crate) fn encode(len: usize, packed: &[u8]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(10 + packed.len());
+    varint::write(len as u64, &mut buffer);
+    let start = buffer.len();
+    buffer.extend_from_slice(&packed[..len.div_ceil(8)]);
+    if let Some(last) = buffer[start..].last_mut() {
+        *last &= 0xff >> ((8 - len % 8) % 8);
+    }
+    buffer
+}
+
+/// Splits an encoded bit set into its length in bits and its packed bytes.
+pub(crate) fn decode(data: &[u8]) -> Result<(usize, &[u8]), AvroBytesError> {
+    let (len, packed) = varint::read(data).ok_or(AvroBytesError::InvalidBitLength)?;
+    let len = usize::try_from(len).map_err(|_| AvroBytesError::InvalidBitLength)?;
+    if packed.len() != len.div_ceil(8) {
+        return Err(AvroBytesError::WrongFixedSize {
+            expected: len.div_ceil(8),
+            actual: packed.len(),
+        });
+    }
+    Ok((len, packed))
+}
+
+#[cfg(feature = "fixedbitset")]
+pub(crate) fn pack_fixedbitset(set: &fixedbitset::FixedBitSet) -> Vec<u8> {
+    let mut packed = vec![0; set.len().div_ceil(8)];
+    for bit in set.ones() {
+        packed[bit / 8] |= 1 << (bit % 8);
+    }
+    encode(set.len(), &packed)
+}
+
+#[cfg(feature = "fixedbitset")]
+pub(crate) fn unpack_fixedbitset(data: &[u8]) -> Result<fixedbitset::FixedBitSet, AvroBytesError> {
+    let (len, packed) = decode(data)?;
+    let mut set = fixedbitset::FixedBitSet::with_capacity(len);
+    for bit in (0..len).filter(|bit| packed[bit / 8] & (1 << (bit % 8)) != 0) {
+        set.insert(bit);
+    }
+    Ok(set)
+}
+
+#[cfg(feature = "bitvec")]
+pub(crate) fn pack_bitvec(bits: &bitvec::vec::BitVec<u8>) -> Vec<u8> {
+    // a vector built from a sub-slice may start in the middle of its first byte
+    let mut bits = bits.clone();
+    bits.force_align();
+    bits.set_uninitialized(false);
+    encode(bits.len(), bits.as_raw_slice())
+}
+
+#[cfg(feature = "bitvec")]
+pub(crate) fn unpack_bitvec(data: &[u8]) -> Result<bitvec::vec::BitVec<u8>, AvroBytesError> {
+    let (len, packed) = decode(data)?;
+    let mut bits = bitvec::vec::BitVec::from_slice(packed);
+    bits.truncate(len);
+    Ok(bits)
+}
@@ -0,0 +1,57 @@
+use crate::avro_bytes::error::Error as AvroBytesError;
+
+/// Checksum appended, little-endian, to the bytes of a checksummed field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Checksum {
+    #[cfg(feature = "crc32c")]
+    Crc32c,
+    #[cfg(feature = "xxhash")]
+    Xxh3,
+}
+
+impl Checksum {
+    fn size(self) -> usize {
+        match self {
+            #[cfg(feature = "crc32c")]
+            Checksum::Crc32c => 4,
+            #[cfg(feature = "xxhash")]
+            Checksum::Xxh3 => 8,
+        }
+    }
+
+    fn compute(self, data: &[u8]) -> u64 {
+        match self {
+            #[cfg(feature = "crc32c")]
+            Checksum::Crc32c => u64::from(crc32c::crc32c(data)),
+            #[cfg(feature = "xxhash")]
+            Checksum::Xxh3 => xxhash_rust::xxh3::xxh3_64(data),
+        }
+    }
+
+    /// Copies `data` followed by its checksum.
+    pub(crate) fn append(self, data: &[u8]) -> Vec<u8> {
+        let size = self.size();
+        let mut buffer = Vec::with_capacity(data.len() + size);
+        buffer.extend_from_slice(data);
+        buffer.extend_from_slice(&self.compute(data).to_le_bytes()[..size]);
+        buffer
+    }
+
+    /// Checks and removes the trailing checksum of `data`.
+    pub(crate) fn verify(self, mut data: Vec<u8>) -> Result<Vec<u8>, AvroBytesError> {
+        let size = self.size();
+        let Some(split) = data.len().checked_sub(size) else {
+            return Err(AvroBytesError::MissingChecksum { length: data.len() });
+        };
+        let mut expected = [0; 8];
+        expected[..size].copy_from_slice(&data[split..]);
+        let expected = u64::from_le_bytes(expected);
+
+        data.truncate(split);
+        let actual = self.compute(&data);
+        if actual != expected {
+            return Err(AvroBytesError::ChecksumMismatch { expected, actual });
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use crate::avro_bytes::error::Error as AvroBytesError;
+
+#[derive(Debug)]
+pub struct CipherError(String);
+
+impl CipherError {
+    pub fn new(reason: impl Display) -> Self {
+        CipherError(reason.to_string())
+    }
+}
+
+impl Display for CipherError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CipherError {}
+
+/// Encryption of the `encrypted` byte fields.
+///
+/// `associated_data` is authenticated along with the ciphertext, which fails to
+/// decrypt with other associated data, see [`with_associated_data`].
+pub trait FieldCipher {
+    fn encrypt(&self, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, CipherError>;
+
+    fn decrypt(&self, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, CipherError>;
+}
+
+thread_local! {
+    static CIPHER: RefCell<Option<Arc<dyn FieldCipher>>> = const { RefCell::new(None) };
+    static ASSOCIATED_DATA: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` with `cipher` encrypting and decrypting the `encrypted` fields of the current thread.
+///
+/// `with` functions cannot take arguments, hence the cipher being passed through this scope.
+pub fn with_cipher<R>(cipher: Arc<dyn FieldCipher>, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Arc<dyn FieldCipher>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CIPHER.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CIPHER.replace(Some(cipher)));
+    f()
+}
+
+/// Runs `f` with `associated_data` bound to the `encrypted` fields of the current thread,
+/// empty otherwise.
+///
+/// Binding a ciphertext to, say, the key of its record prevents it from being copied
+/// into another record and still decrypting. As `with` functions do not know the name
+/// of their field, the fields of a record encrypted in the same scope share the same
+/// associated data.
+pub fn with_associated_data<R>(associated_data: &[u8], f: impl FnOnce() -> R) -> R {
+    struct Restore(Vec<u8>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            ASSOCIATED_DATA.set(std::mem::take(&mut self.0));
+        }
+    }
+
+    let _restore = Restore(ASSOCIATED_DATA.replace(associated_data.to_vec()));
+    f()
+}
+
+fn current() -> Result<Arc<dyn FieldCipher>, AvroBytesError> {
+    CIPHER
+        .with_borrow(Option::clone)
+        .ok_or(AvroBytesError::MissingCipher)
+}
+
+pub(crate) fn encrypt(plaintext: &[u8]) -> Result<Vec<u8>, AvroBytesError> {
+    let cipher = current()?;
+    ASSOCIATED_DATA
+        .with_borrow(|associated_data| cipher.encrypt(plaintext, associated_data))
+        .map_err(|err| AvroBytesError::Cipher(err.0))
+}
+
+pub(crate) fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>, AvroBytesError> {
+    let cipher = current()?;
+    ASSOCIATED_DATA
+        .with_borrow(|associated_data| cipher.decrypt(ciphertext, associated_data))
+        .map_err(|err| AvroBytesError::Cipher(err.0))
+}
+
+/// Leaves the bytes untouched, for tests.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopCipher;
+
+impl FieldCipher for NoopCipher {
+    fn encrypt(&self, plaintext: &[u8], _: &[u8]) -> Result<Vec<u8>, CipherError> {
+        Ok(plaintext.to_vec())
+    }
+
+    fn decrypt(&self, ciphertext: &[u8], _: &[u8]) -> Result<Vec<u8>, CipherError> {
+        Ok(ciphertext.to_vec())
+    }
+}
+
+/// AES-256-GCM with a random 96 bits nonce stored before the ciphertext, and the
+/// associated data authenticated with it.
+#[cfg(feature = "aes-gcm")]
+#[derive(Clone)]
+pub struct Aes256GcmCipher {
+    cipher: aes_gcm::Aes256Gcm,
+}
+
+#[cfg(feature = "aes-gcm")]
+impl Aes256GcmCipher {
+    const NONCE_SIZE: usize = 12;
+
+    pub fn new(key: &[u8; 32]) -> Self {
+        use aes_gcm::KeyInit;
+
+        Aes256GcmCipher {
+            cipher: aes_gcm::Aes256Gcm::new(key.into()),
+        }
+    }
+}
+
+#[cfg(feature = "aes-gcm")]
+impl FieldCipher for Aes256GcmCipher {
+    fn encrypt(&self, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, CipherError> {
+        use aes_gcm::aead::{Aead, AeadCore, OsRng, Payload};
+
+        let nonce = aes_gcm::Aes256Gcm::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: plaintext,
+            aad: associated_data,
+        };
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, payload)
+            .map_err(CipherError::new)?;
+        let mut message = Vec::with_capacity(Self::NONCE_SIZE + ciphertext.len());
+        message.extend_from_slice(&nonce);
+        message.extend_from_slice(&ciphertext);
+        Ok(message)
+    }
+
+    fn decrypt(&self, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, CipherError> {
+        use aes_gcm::aead::{Aead, Payload};
+
+        if ciphertext.len() < Self::NONCE_SIZE {
+            return Err(CipherError::new("ciphertext shorter than its nonce"));
+        }
+        let (nonce, ciphertext) = ciphertext.split_at(Self::NONCE_SIZE);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: associated_data,
+        };
+        self.cipher
+            .decrypt(nonce.into(), payload)
+            .map_err(CipherError::new)
+    }
+}
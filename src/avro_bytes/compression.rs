@@ -0,0 +1,138 @@
+use std::cell::Cell;
+
+use crate::avro_bytes::{error::Error as AvroBytesError, varint};
+
+/// Largest decompressed size accepted by default, 64 MiB.
+pub const DEFAULT_LIMIT: usize = 64 * 1024 * 1024;
+
+thread_local! {
+    static LIMIT: Cell<usize> = const { Cell::new(DEFAULT_LIMIT) };
+}
+
+/// Runs `f` with `limit` as the largest decompressed size accepted on the current thread.
+pub fn with_limit<R>(limit: usize, f: impl FnOnce() -> R) -> R {
+    struct Restore(usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LIMIT.set(self.0);
+        }
+    }
+
+    let _restore = Restore(LIMIT.replace(limit));
+    f()
+}
+
+/// Codec identified by the first byte of a compressed field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Codec {
+    #[cfg(feature = "deflate")]
+    Deflate = 1,
+    #[cfg(feature = "snappy")]
+    Snappy = 2,
+    #[cfg(feature = "zstd")]
+    Zstd = 3,
+}
+
+impl Codec {
+    fn from_header(header: u8) -> Option<Codec> {
+        match header {
+            #[cfg(feature = "deflate")]
+            1 => Some(Codec::Deflate),
+            #[cfg(feature = "snappy")]
+            2 => Some(Codec::Snappy),
+            #[cfg(feature = "zstd")]
+            3 => Some(Codec::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// Compresses `data` behind a header made of the codec byte and the decompressed length.
+pub(crate) fn compress(codec: Codec, data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    let mut buffer = vec![codec as u8];
+    varint::write(data.len() as u64, &mut buffer);
+    match codec {
+        #[cfg(feature = "deflate")]
+        Codec::Deflate => {
+            let mut encoder = libflate::deflate::Encoder::new(buffer);
+            std::io::Write::write_all(&mut encoder, data)?;
+            encoder.finish().into_result()
+        }
+        #[cfg(feature = "snappy")]
+        Codec::Snappy => {
+            let compressed = snap::raw::Encoder::new().compress_vec(data)?;
+            buffer.extend_from_slice(&compressed);
+            Ok(buffer)
+        }
+        #[cfg(feature = "zstd")]
+        Codec::Zstd => {
+            buffer.extend_from_slice(&zstd::bulk::compress(data, 0)?);
+            Ok(buffer)
+        }
+    }
+}
+
+/// Decompresses a field, refusing it before decompression when the announced length
+/// exceeds the limit of the current thread.
+pub(crate) fn decompress(data: &[u8]) -> Result<Vec<u8>, AvroBytesError> {
+    let (&header, rest) = data
+        .split_first()
+        .ok_or_else(|| AvroBytesError::Decompression("missing codec header".to_string()))?;
+    let codec = Codec::from_header(header).ok_or(AvroBytesError::UnknownCodec(header))?;
+    let (length, payload) = varint::read(rest)
+        .ok_or_else(|| AvroBytesError::Decompression("invalid length header".to_string()))?;
+    let length = usize::try_from(length).unwrap_or(usize::MAX);
+    let limit = LIMIT.get();
+    if length > limit {
+        return Err(AvroBytesError::LengthLimit { length, limit });
+    }
+
+    let decompressed = match codec {
+        #[cfg(feature = "deflate")]
+        Codec::Deflate => read_at_most(libflate::deflate::Decoder::new(payload), length)?,
+        #[cfg(feature = "snappy")]
+        Codec::Snappy => {
+            // the raw snappy format announces its length, checked before allocating
+            if snap::raw::decompress_len(payload).map_err(corrupt)? != length {
+                return Err(mismatch(length));
+            }
+            snap::raw::Decoder::new()
+                .decompress_vec(payload)
+                .map_err(corrupt)?
+        }
+        #[cfg(feature = "zstd")]
+        Codec::Zstd => read_at_most(
+            zstd::stream::read::Decoder::new(payload).map_err(corrupt)?,
+            length,
+        )?,
+    };
+
+    if decompressed.len() != length {
+        return Err(mismatch(length));
+    }
+    Ok(decompressed)
+}
+
+fn corrupt(err: impl std::fmt::Display) -> AvroBytesError {
+    AvroBytesError::Decompression(err.to_string())
+}
+
+fn mismatch(length: usize) -> AvroBytesError {
+    AvroBytesError::Decompression(format!(
+        "decompressed data does not have the announced length of {length} bytes"
+    ))
+}
+
+/// Reads one byte past `length` so that longer data is detected without reading all of it.
+#[cfg(any(feature = "deflate", feature = "zstd"))]
+fn read_at_most(reader: impl std::io::Read, length: usize) -> Result<Vec<u8>, AvroBytesError> {
+    use std::io::Read;
+
+    let mut buffer = Vec::with_capacity(length);
+    reader
+        .take(length as u64 + 1)
+        .read_to_end(&mut buffer)
+        .map_err(corrupt)?;
+    Ok(buffer)
+}
@@ -0,0 +1,97 @@
+use crate::avro_bytes::error::Error as AvroBytesError;
+use serde::de::{Error, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+
+#[cfg(feature = "bitvec")]
+use crate::avro_bytes::bits::unpack_bitvec;
+#[cfg(feature = "bitvec")]
+use bitvec::vec::BitVec;
+
+#[cfg(feature = "fixedbitset")]
+use crate::avro_bytes::bits::unpack_fixedbitset;
+#[cfg(feature = "fixedbitset")]
+use fixedbitset::FixedBitSet;
+
+/// Decodes bytes with `unpack`, shared by the bit set types.
+struct BitsVisitor<T>(fn(&[u8]) -> Result<T, AvroBytesError>);
+
+impl<'de, T> Visitor<'de> for BitsVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "bytes holding a bit length and packed bits")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        (self.0)(v).map_err(AvroBytesError::into_de)
+    }
+}
+
+struct OptionBitsVisitor<T>(fn(&[u8]) -> Result<T, AvroBytesError>);
+
+impl<'de, T> Visitor<'de> for OptionBitsVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "null or bytes holding a bit length and packed bits"
+        )
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserializer.deserialize_bytes(BitsVisitor(self.0))?))
+    }
+}
+
+#[cfg(feature = "bitvec")]
+#[allow(unused)]
+pub fn deserialize_bitvec<'de, D>(deserializer: D) -> Result<BitVec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(BitsVisitor(unpack_bitvec))
+}
+
+#[cfg(feature = "bitvec")]
+#[allow(unused)]
+pub fn deserialize_option_bitvec<'de, D>(deserializer: D) -> Result<Option<BitVec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptionBitsVisitor(unpack_bitvec))
+}
+
+#[cfg(feature = "fixedbitset")]
+#[allow(unused)]
+pub fn deserialize_fixedbitset<'de, D>(deserializer: D) -> Result<FixedBitSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(BitsVisitor(unpack_fixedbitset))
+}
+
+#[cfg(feature = "fixedbitset")]
+#[allow(unused)]
+pub fn deserialize_option_fixedbitset<'de, D>(
+    deserializer: D,
+) -> Result<Option<FixedBitSet>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptionBitsVisitor(unpack_fixedbitset))
+}
@@ -7,6 +7,11 @@ use serde::{
     Deserializer,
 };
 
+use crate::avro_bytes::{
+    de::cautious,
+    error::{at_index, Error as AvroBytesError},
+};
+
 #[allow(unused)]
 pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BString, D::Error> {
     struct BStringVisitor;
@@ -20,7 +25,7 @@ pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BString
 
         #[inline]
         fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<BString, V::Error> {
-            let capacity = seq.size_hint().unwrap_or_default();
+            let capacity = cautious(seq.size_hint());
             let mut bytes = Vec::with_capacity(capacity);
             while let Some(v) = seq.next_element()? {
                 bytes.push(v);
@@ -98,9 +103,12 @@ pub fn deserialize_list<'de, D: Deserializer<'de>>(
         fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
             use crate::de::map::Bytes;
 
-            let capacity = seq.size_hint().unwrap_or_default();
+            let capacity = cautious(seq.size_hint());
             let mut items = Vec::with_capacity(capacity);
-            while let Some(bytes) = seq.next_element::<Bytes>()? {
+            while let Some(bytes) = seq
+                .next_element::<Bytes>()
+                .map_err(|err| at_index(items.len(), err))?
+            {
                 items.push(BString::from(bytes.0));
             }
             Ok(items)
@@ -156,14 +164,20 @@ pub fn deserialize_hashmap<'de, D: Deserializer<'de>>(
         fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
             use crate::de::map::Pair;
 
-            let capacity = seq.size_hint().unwrap_or_default();
+            let capacity = cautious(seq.size_hint());
             let mut items = HashMap::with_capacity(capacity);
-            while let Some(Pair { key, value }) = seq.next_element::<Pair>()? {
-                if let Some(duplicated) = items.insert(BString::new(key.0), BString::new(value.0)) {
-                    return Err(serde::de::Error::custom(format!(
-                        "unexpected duplicate key: `{duplicated}`"
-                    )));
+            while let Some(Pair { key, value }) = seq
+                .next_element::<Pair>()
+                .map_err(|err| at_index(items.len(), err))?
+            {
+                let key = BString::new(key.0);
+                if items.contains_key(&key) {
+                    return Err(at_index(
+                        items.len(),
+                        AvroBytesError::DuplicateKey(key.into()).into_de(),
+                    ));
                 }
+                items.insert(key, BString::new(value.0));
             }
             Ok(items)
         }
@@ -219,12 +233,18 @@ pub fn deserialize_btreemap<'de, D: Deserializer<'de>>(
             use crate::de::map::Pair;
 
             let mut items = BTreeMap::new();
-            while let Some(Pair { key, value }) = seq.next_element::<Pair>()? {
-                if let Some(duplicated) = items.insert(BString::new(key.0), BString::new(value.0)) {
-                    return Err(serde::de::Error::custom(format!(
-                        "unexpected duplicate key: `{duplicated}`"
-                    )));
+            while let Some(Pair { key, value }) = seq
+                .next_element::<Pair>()
+                .map_err(|err| at_index(items.len(), err))?
+            {
+                let key = BString::new(key.0);
+                if items.contains_key(&key) {
+                    return Err(at_index(
+                        items.len(),
+                        AvroBytesError::DuplicateKey(key.into()).into_de(),
+                    ));
                 }
+                items.insert(key, BString::new(value.0));
             }
             Ok(items)
         }
@@ -1,3 +1,5 @@
+use crate::avro_bytes::de::cautious;
+use crate::avro_bytes::error::Error as AvroBytesError;
 use serde::de::{Error, SeqAccess, Visitor};
 use serde::Deserializer;
 use std::fmt::Formatter;
@@ -26,7 +28,7 @@ impl<'de> Visitor<'de> for BytesVisitor {
     type Value = Vec<u8>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode bytes")
+        write!(formatter, "bytes or a legacy array of int")
     }
 
     fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
@@ -36,11 +38,18 @@ impl<'de> Visitor<'de> for BytesVisitor {
         Ok(v.to_vec())
     }
 
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut data = vec![];
+        let mut data = Vec::with_capacity(cautious(seq.size_hint()));
         while let Some(x) = seq.next_element()? {
             data.push(x);
         }
@@ -54,7 +63,7 @@ impl<'de> Visitor<'de> for OptionBytesVisitor {
     type Value = Option<Vec<u8>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode option of bytes")
+        write!(formatter, "null or bytes")
     }
 
     fn visit_none<E>(self) -> Result<Self::Value, E>
@@ -71,3 +80,94 @@ impl<'de> Visitor<'de> for OptionBytesVisitor {
         Ok(Some(deserialize_bytes(deserializer)?))
     }
 }
+
+#[allow(unused)]
+pub fn deserialize_strict_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = StrictBytesVisitor;
+    // apache-avro only hands an `array<int>` value to `visit_seq` through `deserialize_any`
+    deserializer.deserialize_any(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_strict_bytes<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionStrictBytesVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+/// Accepts legacy `array<int>` input but reports the index and value of
+/// any element which does not fit in a `u8`.
+///
+/// As apache-avro does not support `bytes` values in `deserialize_any`, genuine
+/// Avro `bytes` are only accepted from self-describing formats: with Avro, this
+/// reads legacy data only.
+pub struct StrictBytesVisitor;
+
+impl<'de> Visitor<'de> for StrictBytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "bytes or a legacy array of int in 0..=255")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut data = Vec::with_capacity(cautious(seq.size_hint()));
+        while let Some(x) = seq.next_element::<i64>()? {
+            let Ok(byte) = u8::try_from(x) else {
+                return Err(AvroBytesError::InvalidByte {
+                    index: data.len(),
+                    value: x,
+                }
+                .into_de());
+            };
+            data.push(byte);
+        }
+        Ok(data)
+    }
+}
+
+struct OptionStrictBytesVisitor;
+
+impl<'de> Visitor<'de> for OptionStrictBytesVisitor {
+    type Value = Option<Vec<u8>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or bytes")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_strict_bytes(deserializer)?))
+    }
+}
@@ -0,0 +1,131 @@
+use serde::de::Error;
+use serde::Deserializer;
+
+use crate::avro_bytes::{
+    checksum::Checksum,
+    de::{
+        bytes::{deserialize_bytes, deserialize_option_bytes},
+        list::{deserialize_list, deserialize_option_list},
+    },
+    error::{at_index, Error as AvroBytesError},
+};
+
+fn verify<E: Error>(checksum: Checksum, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+    checksum.verify(bytes).map_err(AvroBytesError::into_de)
+}
+
+fn verify_list<E: Error>(checksum: Checksum, items: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, E> {
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, bytes)| verify(checksum, bytes).map_err(|err| at_index(index, err)))
+        .collect()
+}
+
+fn deserialize_checksummed<'de, D: Deserializer<'de>>(
+    checksum: Checksum,
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error> {
+    verify(checksum, deserialize_bytes(deserializer)?)
+}
+
+fn deserialize_option_checksummed<'de, D: Deserializer<'de>>(
+    checksum: Checksum,
+    deserializer: D,
+) -> Result<Option<Vec<u8>>, D::Error> {
+    deserialize_option_bytes(deserializer)?
+        .map(|bytes| verify(checksum, bytes))
+        .transpose()
+}
+
+fn deserialize_list_checksummed<'de, D: Deserializer<'de>>(
+    checksum: Checksum,
+    deserializer: D,
+) -> Result<Vec<Vec<u8>>, D::Error> {
+    verify_list(checksum, deserialize_list(deserializer)?)
+}
+
+fn deserialize_option_list_checksummed<'de, D: Deserializer<'de>>(
+    checksum: Checksum,
+    deserializer: D,
+) -> Result<Option<Vec<Vec<u8>>>, D::Error> {
+    deserialize_option_list(deserializer)?
+        .map(|items| verify_list(checksum, items))
+        .transpose()
+}
+
+#[cfg(feature = "crc32c")]
+#[allow(unused)]
+pub fn deserialize_crc32c_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_checksummed(Checksum::Crc32c, deserializer)
+}
+
+#[cfg(feature = "crc32c")]
+#[allow(unused)]
+pub fn deserialize_option_crc32c_bytes<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_checksummed(Checksum::Crc32c, deserializer)
+}
+
+#[cfg(feature = "crc32c")]
+#[allow(unused)]
+pub fn deserialize_list_crc32c_bytes<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_list_checksummed(Checksum::Crc32c, deserializer)
+}
+
+#[cfg(feature = "crc32c")]
+#[allow(unused)]
+pub fn deserialize_option_list_crc32c_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list_checksummed(Checksum::Crc32c, deserializer)
+}
+
+#[cfg(feature = "xxhash")]
+#[allow(unused)]
+pub fn deserialize_xxh3_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_checksummed(Checksum::Xxh3, deserializer)
+}
+
+#[cfg(feature = "xxhash")]
+#[allow(unused)]
+pub fn deserialize_option_xxh3_bytes<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_checksummed(Checksum::Xxh3, deserializer)
+}
+
+#[cfg(feature = "xxhash")]
+#[allow(unused)]
+pub fn deserialize_list_xxh3_bytes<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_list_checksummed(Checksum::Xxh3, deserializer)
+}
+
+#[cfg(feature = "xxhash")]
+#[allow(unused)]
+pub fn deserialize_option_list_xxh3_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list_checksummed(Checksum::Xxh3, deserializer)
+}
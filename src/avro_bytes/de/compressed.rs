@@ -0,0 +1,66 @@
+use crate::avro_bytes::compression::decompress;
+use crate::avro_bytes::error::Error as AvroBytesError;
+use serde::de::{Error, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+
+#[allow(unused)]
+pub fn deserialize_compressed_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = CompressedBytesVisitor;
+    deserializer.deserialize_bytes(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_compressed_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionCompressedBytesVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+struct CompressedBytesVisitor;
+
+impl<'de> Visitor<'de> for CompressedBytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "compressed bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        decompress(v).map_err(AvroBytesError::into_de)
+    }
+}
+
+struct OptionCompressedBytesVisitor;
+
+impl<'de> Visitor<'de> for OptionCompressedBytesVisitor {
+    type Value = Option<Vec<u8>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or compressed bytes")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_compressed_bytes(deserializer)?))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::avro_bytes::embedded::Avro;
+use crate::avro_bytes::error::Error as AvroBytesError;
+use apache_avro::AvroSchema;
+use serde::de::{DeserializeOwned, Error, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+
+#[cfg(feature = "bincode")]
+use crate::avro_bytes::embedded::Bincode;
+#[cfg(any(feature = "bincode", feature = "json", feature = "msgpack"))]
+use crate::avro_bytes::embedded::Format;
+#[cfg(feature = "json")]
+use crate::avro_bytes::embedded::Json;
+#[cfg(feature = "msgpack")]
+use crate::avro_bytes::embedded::MessagePack;
+
+/// Decodes bytes holding a value of type `T`.
+struct EmbeddedVisitor<T>(fn(&[u8]) -> Result<T, String>);
+
+impl<'de, T> Visitor<'de> for EmbeddedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "bytes holding an embedded value")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        (self.0)(v).map_err(|err| AvroBytesError::Embedded(err).into_de())
+    }
+}
+
+struct OptionEmbeddedVisitor<T>(fn(&[u8]) -> Result<T, String>);
+
+impl<'de, T> Visitor<'de> for OptionEmbeddedVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or bytes holding an embedded value")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(
+            deserializer.deserialize_bytes(EmbeddedVisitor(self.0))?,
+        ))
+    }
+}
+
+#[cfg(any(feature = "bincode", feature = "json", feature = "msgpack"))]
+fn deserialize_embedded<'de, D, F, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    F: Format,
+    T: DeserializeOwned,
+{
+    deserializer.deserialize_bytes(EmbeddedVisitor(F::decode::<T>))
+}
+
+#[cfg(any(feature = "bincode", feature = "json", feature = "msgpack"))]
+fn deserialize_option_embedded<'de, D, F, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    F: Format,
+    T: DeserializeOwned,
+{
+    deserializer.deserialize_option(OptionEmbeddedVisitor(F::decode::<T>))
+}
+
+#[cfg(feature = "bincode")]
+#[allow(unused)]
+pub fn deserialize_bincode<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    deserialize_embedded::<D, Bincode, T>(deserializer)
+}
+
+#[cfg(feature = "bincode")]
+#[allow(unused)]
+pub fn deserialize_option_bincode<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    deserialize_option_embedded::<D, Bincode, T>(deserializer)
+}
+
+#[cfg(feature = "json")]
+#[allow(unused)]
+pub fn deserialize_json<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    deserialize_embedded::<D, Json, T>(deserializer)
+}
+
+#[cfg(feature = "json")]
+#[allow(unused)]
+pub fn deserialize_option_json<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    deserialize_option_embedded::<D, Json, T>(deserializer)
+}
+
+#[cfg(feature = "msgpack")]
+#[allow(unused)]
+pub fn deserialize_msgpack<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    deserialize_embedded::<D, MessagePack, T>(deserializer)
+}
+
+#[cfg(feature = "msgpack")]
+#[allow(unused)]
+pub fn deserialize_option_msgpack<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    deserialize_option_embedded::<D, MessagePack, T>(deserializer)
+}
+
+#[allow(unused)]
+pub fn deserialize_avro<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + AvroSchema,
+{
+    deserializer.deserialize_bytes(EmbeddedVisitor(Avro::decode::<T>))
+}
+
+#[allow(unused)]
+pub fn deserialize_option_avro<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + AvroSchema,
+{
+    deserializer.deserialize_option(OptionEmbeddedVisitor(Avro::decode::<T>))
+}
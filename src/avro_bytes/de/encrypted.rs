@@ -0,0 +1,63 @@
+use serde::de::Error;
+use serde::Deserializer;
+
+use crate::avro_bytes::{
+    cipher::decrypt,
+    de::{
+        bytes::{deserialize_bytes, deserialize_option_bytes},
+        list::{deserialize_list, deserialize_option_list},
+    },
+    error::{at_index, Error as AvroBytesError},
+};
+
+fn decrypt_bytes<E: Error>(bytes: &[u8]) -> Result<Vec<u8>, E> {
+    decrypt(bytes).map_err(AvroBytesError::into_de)
+}
+
+fn decrypt_list<E: Error>(items: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, E> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, bytes)| decrypt_bytes(bytes).map_err(|err| at_index(index, err)))
+        .collect()
+}
+
+#[allow(unused)]
+pub fn deserialize_encrypted_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    decrypt_bytes(&deserialize_bytes(deserializer)?)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_encrypted_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_bytes(deserializer)?
+        .map(|bytes| decrypt_bytes(&bytes))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_list_encrypted_bytes<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    decrypt_list(deserialize_list(deserializer)?)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_list_encrypted_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list(deserializer)?
+        .map(decrypt_list)
+        .transpose()
+}
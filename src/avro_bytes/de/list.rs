@@ -1,4 +1,6 @@
+use crate::avro_bytes::de::cautious;
 use crate::avro_bytes::de::map::Bytes;
+use crate::avro_bytes::error::at_index;
 use serde::de::{Error, SeqAccess, Visitor};
 use serde::Deserializer;
 use std::fmt::Formatter;
@@ -18,15 +20,18 @@ impl<'de> Visitor<'de> for ListVisitor {
     type Value = Vec<Vec<u8>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode list of bytes")
+        write!(formatter, "an array of bytes")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut lists = vec![];
-        while let Some(list) = seq.next_element::<Bytes>()? {
+        let mut lists = Vec::with_capacity(cautious(seq.size_hint()));
+        while let Some(list) = seq
+            .next_element::<Bytes>()
+            .map_err(|err| at_index(lists.len(), err))?
+        {
             lists.push(list.0);
         }
         Ok(lists)
@@ -48,7 +53,7 @@ impl<'de> Visitor<'de> for OptionListVisitor {
     type Value = Option<Vec<Vec<u8>>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode option of list of bytes")
+        write!(formatter, "null or an array of bytes")
     }
 
     fn visit_none<E>(self) -> Result<Self::Value, E>
@@ -8,7 +8,10 @@ use serde::{
     Deserialize, Deserializer,
 };
 
-use crate::avro_bytes::de::bytes::BytesVisitor;
+use crate::avro_bytes::{
+    de::cautious,
+    error::{at_index, at_key, Error as AvroBytesError},
+};
 
 #[derive(Debug)]
 pub(crate) struct Pair {
@@ -16,17 +19,7 @@ pub(crate) struct Pair {
     pub(crate) value: Bytes,
 }
 
-#[derive(Debug)]
-pub struct Bytes(pub(crate) Vec<u8>);
-
-impl<'de> Deserialize<'de> for Bytes {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        Ok(Bytes(deserializer.deserialize_bytes(BytesVisitor)?))
-    }
-}
+pub use crate::types::AvroBytes as Bytes;
 
 impl<'de> Deserialize<'de> for Pair {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -43,7 +36,10 @@ impl<'de> Visitor<'de> for VisitorPair {
     type Value = Pair;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode Pair")
+        write!(
+            formatter,
+            "a Pair record with `key` and `value` bytes fields"
+        )
     }
 
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -57,17 +53,23 @@ impl<'de> Visitor<'de> for VisitorPair {
             let key_str = key_str.as_str();
             match key_str {
                 "key" => key = Some(map.next_value::<Bytes>()?),
-                "value" => value = Some(map.next_value::<Bytes>()?),
+                "value" => {
+                    let decoded = map.next_value::<Bytes>();
+                    value = Some(match &key {
+                        Some(key) => decoded.map_err(|err| at_key(key, err))?,
+                        None => decoded?,
+                    })
+                }
                 _ => {
                     map.next_value::<IgnoredAny>()?;
                 }
             }
         }
         let Some(key) = key else {
-            return Err(Error::missing_field("key"));
+            return Err(AvroBytesError::MissingPairField("key").into_de());
         };
         let Some(value) = value else {
-            return Err(Error::missing_field("value"));
+            return Err(AvroBytesError::MissingPairField("value").into_de());
         };
         Ok(Pair { key, value })
     }
@@ -88,17 +90,28 @@ impl<'de> Visitor<'de> for HashMapVisitor {
     type Value = HashMap<Vec<u8>, Vec<u8>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode option of hashmap")
+        write!(formatter, "an array of Pair records")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut map = HashMap::new();
-
-        while let Some(Pair { key, value }) = seq.next_element::<Pair>()? {
+        let mut map = HashMap::with_capacity(cautious(seq.size_hint()));
+        let mut index = 0;
+
+        while let Some(Pair { key, value }) = seq
+            .next_element::<Pair>()
+            .map_err(|err| at_index(index, err))?
+        {
+            if map.contains_key(&key.0) {
+                return Err(at_index(
+                    index,
+                    AvroBytesError::DuplicateKey(key.0).into_de(),
+                ));
+            }
             map.insert(key.0, value.0);
+            index += 1;
         }
 
         Ok(map)
@@ -120,7 +133,7 @@ impl<'de> Visitor<'de> for BtreeMapVisitor {
     type Value = BTreeMap<Vec<u8>, Vec<u8>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode btreemap")
+        write!(formatter, "an array of Pair records")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -128,16 +141,27 @@ impl<'de> Visitor<'de> for BtreeMapVisitor {
         A: SeqAccess<'de>,
     {
         let mut map = BTreeMap::new();
-
-        while let Some(Pair { key, value }) = seq.next_element::<Pair>()? {
+        let mut index = 0;
+
+        while let Some(Pair { key, value }) = seq
+            .next_element::<Pair>()
+            .map_err(|err| at_index(index, err))?
+        {
+            if map.contains_key(&key.0) {
+                return Err(at_index(
+                    index,
+                    AvroBytesError::DuplicateKey(key.0).into_de(),
+                ));
+            }
             map.insert(key.0, value.0);
+            index += 1;
         }
 
         Ok(map)
     }
 }
 
-#[allow(unused)]
+#[allow(unused, clippy::type_complexity)]
 pub fn deserialize_option_hashmap<'de, D>(
     deserializer: D,
 ) -> Result<Option<HashMap<Vec<u8>, Vec<u8>>>, D::Error>
@@ -148,7 +172,7 @@ where
     deserializer.deserialize_option(visitor)
 }
 
-#[allow(unused)]
+#[allow(unused, clippy::type_complexity)]
 pub fn deserialize_option_btreemap<'de, D>(
     deserializer: D,
 ) -> Result<Option<BTreeMap<Vec<u8>, Vec<u8>>>, D::Error>
@@ -165,7 +189,7 @@ impl<'de> Visitor<'de> for OptionHashMapVisitor {
     type Value = Option<HashMap<Vec<u8>, Vec<u8>>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode option of hashmap")
+        write!(formatter, "null or an array of Pair records")
     }
 
     fn visit_none<E>(self) -> Result<Self::Value, E>
@@ -189,7 +213,7 @@ impl<'de> Visitor<'de> for OptionBtreeMapVisitor {
     type Value = Option<BTreeMap<Vec<u8>, Vec<u8>>>;
 
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "Unable to decode option of btreemap")
+        write!(formatter, "null or an array of Pair records")
     }
 
     fn visit_none<E>(self) -> Result<Self::Value, E>
@@ -1,6 +1,28 @@
+#[cfg(any(feature = "bitvec", feature = "fixedbitset"))]
+pub mod bits;
 pub mod bytes;
+#[cfg(any(feature = "crc32c", feature = "xxhash"))]
+pub mod checksummed;
+#[cfg(any(feature = "deflate", feature = "snappy", feature = "zstd"))]
+pub mod compressed;
+pub mod embedded;
+pub mod encrypted;
 pub mod list;
 pub mod map;
+pub mod packed;
+pub mod tuple;
+pub mod utf8;
+
+#[cfg(unix)]
+pub mod path;
 
 #[cfg(feature = "bstr")]
 pub mod bstr;
+#[cfg(feature = "secret")]
+pub mod secret;
+
+/// Capacity preallocated from the size hint of an input sequence, which is not trusted
+/// beyond a few thousand elements.
+pub(crate) fn cautious(hint: Option<usize>) -> usize {
+    hint.unwrap_or_default().min(4096)
+}
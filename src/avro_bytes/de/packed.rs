@@ -0,0 +1,136 @@
+use crate::avro_bytes::error::Error as AvroBytesError;
+use crate::avro_bytes::packed::{unpack, unpack_delta, Delta, Packed};
+use serde::de::{Error, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+#[allow(unused)]
+pub fn deserialize_packed<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Packed,
+{
+    let visitor = PackedVisitor(PhantomData);
+    deserializer.deserialize_bytes(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_packed<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Packed,
+{
+    let visitor = OptionPackedVisitor(PhantomData);
+    deserializer.deserialize_option(visitor)
+}
+
+struct PackedVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Packed> Visitor<'de> for PackedVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "bytes of {}-byte little-endian elements",
+            T::SIZE
+        )
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        unpack(v).map_err(AvroBytesError::into_de)
+    }
+}
+
+struct OptionPackedVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Packed> Visitor<'de> for OptionPackedVisitor<T> {
+    type Value = Option<Vec<T>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "null or bytes of {}-byte little-endian elements",
+            T::SIZE
+        )
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_packed(deserializer)?))
+    }
+}
+
+#[allow(unused)]
+pub fn deserialize_delta<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Delta,
+{
+    let visitor = DeltaVisitor(PhantomData);
+    deserializer.deserialize_bytes(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_delta<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Delta,
+{
+    let visitor = OptionDeltaVisitor(PhantomData);
+    deserializer.deserialize_option(visitor)
+}
+
+struct DeltaVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Delta> Visitor<'de> for DeltaVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "bytes of delta-encoded integers")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        unpack_delta(v).map_err(AvroBytesError::into_de)
+    }
+}
+
+struct OptionDeltaVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Delta> Visitor<'de> for OptionDeltaVisitor<T> {
+    type Value = Option<Vec<T>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or bytes of delta-encoded integers")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_delta(deserializer)?))
+    }
+}
@@ -0,0 +1,52 @@
+use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+use serde::Deserializer;
+
+use crate::avro_bytes::de::{
+    bytes::{deserialize_bytes, deserialize_option_bytes},
+    list::{deserialize_list, deserialize_option_list},
+};
+
+fn from_bytes<T: From<OsString>>(bytes: Vec<u8>) -> T {
+    T::from(OsString::from_vec(bytes))
+}
+
+#[allow(unused)]
+pub fn deserialize_path<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<OsString>,
+{
+    deserialize_bytes(deserializer).map(from_bytes)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_path<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<OsString>,
+{
+    Ok(deserialize_option_bytes(deserializer)?.map(from_bytes))
+}
+
+#[allow(unused)]
+pub fn deserialize_list_path<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<OsString>,
+{
+    Ok(deserialize_list(deserializer)?
+        .into_iter()
+        .map(from_bytes)
+        .collect())
+}
+
+#[allow(unused)]
+pub fn deserialize_option_list_path<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<OsString>,
+{
+    Ok(deserialize_option_list(deserializer)?
+        .map(|paths| paths.into_iter().map(from_bytes).collect()))
+}
@@ -0,0 +1,417 @@
+use crate::avro_bytes::de::cautious;
+use crate::avro_bytes::de::map::Bytes;
+use crate::avro_bytes::error::{at_index, at_key, Error as AvroBytesError};
+use ::secrecy::SecretVec;
+use serde::de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Formatter;
+use zeroize::Zeroizing;
+
+#[allow(unused)]
+pub fn deserialize_zeroizing_bytes<'de, D>(deserializer: D) -> Result<Zeroizing<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = ZeroizingBytesVisitor;
+    deserializer.deserialize_bytes(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Zeroizing<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionZeroizingBytesVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_list_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Vec<Zeroizing<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = ListZeroizingBytesVisitor;
+    deserializer.deserialize_seq(visitor)
+}
+
+#[allow(unused, clippy::type_complexity)]
+pub fn deserialize_option_list_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Zeroizing<Vec<u8>>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionListZeroizingBytesVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+#[allow(unused)]
+pub fn deserialize_secret_bytes<'de, D>(deserializer: D) -> Result<SecretVec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_zeroizing_bytes(deserializer).map(into_secret)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_secret_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<SecretVec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(deserialize_option_zeroizing_bytes(deserializer)?.map(into_secret))
+}
+
+/// Moves the buffer into a secret without copying it.
+fn into_secret(mut bytes: Zeroizing<Vec<u8>>) -> SecretVec<u8> {
+    SecretVec::new(std::mem::take(&mut *bytes))
+}
+
+/// Appends `byte`, moving the content to a larger buffer and wiping the previous one
+/// rather than letting `Vec` reallocate.
+fn push(data: &mut Zeroizing<Vec<u8>>, byte: u8) {
+    if data.len() == data.capacity() {
+        let mut grown = Zeroizing::new(Vec::with_capacity((data.capacity() * 2).max(8)));
+        grown.extend_from_slice(data);
+        std::mem::swap(data, &mut grown);
+    }
+    data.push(byte);
+}
+
+struct ZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for ZeroizingBytesVisitor {
+    type Value = Zeroizing<Vec<u8>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "bytes or a legacy array of int")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Zeroizing::new(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Zeroizing::new(v))
+    }
+
+    /// Legacy arrays of integers, only handed to this visitor by self-describing formats:
+    /// apache-avro refuses an `array<int>` value for a bytes field.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut data = Zeroizing::new(Vec::with_capacity(cautious(seq.size_hint())));
+        while let Some(x) = seq.next_element()? {
+            push(&mut data, x);
+        }
+        Ok(data)
+    }
+}
+
+struct OptionZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for OptionZeroizingBytesVisitor {
+    type Value = Option<Zeroizing<Vec<u8>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or bytes")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_zeroizing_bytes(deserializer)?))
+    }
+}
+
+struct ZeroizingBytes(Zeroizing<Vec<u8>>);
+
+impl<'de> Deserialize<'de> for ZeroizingBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_zeroizing_bytes(deserializer).map(ZeroizingBytes)
+    }
+}
+
+struct ListZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for ListZeroizingBytesVisitor {
+    type Value = Vec<Zeroizing<Vec<u8>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "an array of bytes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // growing the list only moves the buffer handles, never the secret bytes
+        let mut list = Vec::with_capacity(cautious(seq.size_hint()));
+        while let Some(bytes) = seq
+            .next_element::<ZeroizingBytes>()
+            .map_err(|err| at_index(list.len(), err))?
+        {
+            list.push(bytes.0);
+        }
+        Ok(list)
+    }
+}
+
+struct OptionListZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for OptionListZeroizingBytesVisitor {
+    type Value = Option<Vec<Zeroizing<Vec<u8>>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or an array of bytes")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_list_zeroizing_bytes(deserializer)?))
+    }
+}
+
+#[allow(unused, clippy::type_complexity)]
+pub fn deserialize_hashmap_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<Vec<u8>, Zeroizing<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = HashMapZeroizingBytesVisitor;
+    deserializer.deserialize_seq(visitor)
+}
+
+#[allow(unused, clippy::type_complexity)]
+pub fn deserialize_option_hashmap_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<Vec<u8>, Zeroizing<Vec<u8>>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionHashMapZeroizingBytesVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+#[allow(unused, clippy::type_complexity)]
+pub fn deserialize_btreemap_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = BTreeMapZeroizingBytesVisitor;
+    deserializer.deserialize_seq(visitor)
+}
+
+#[allow(unused, clippy::type_complexity)]
+pub fn deserialize_option_btreemap_zeroizing_bytes<'de, D>(
+    deserializer: D,
+) -> Result<Option<BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionBTreeMapZeroizingBytesVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+/// Map entry whose value is decoded straight into a zeroizing buffer, which is
+/// then moved into the map without any copy.
+struct ZeroizingPair {
+    key: Vec<u8>,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl<'de> Deserialize<'de> for ZeroizingPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Pair", &[], ZeroizingPairVisitor)
+    }
+}
+
+struct ZeroizingPairVisitor;
+
+impl<'de> Visitor<'de> for ZeroizingPairVisitor {
+    type Value = ZeroizingPair;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a Pair record with `key` and `value` bytes fields"
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut key = None::<Vec<u8>>;
+        let mut value = None::<Zeroizing<Vec<u8>>>;
+
+        while let Some(field) = map.next_key::<String>()? {
+            match field.as_str() {
+                "key" => key = Some(map.next_value::<Bytes>()?.0),
+                "value" => {
+                    let decoded = map.next_value::<ZeroizingBytes>();
+                    value = Some(match &key {
+                        Some(key) => decoded.map_err(|err| at_key(key, err))?.0,
+                        None => decoded?.0,
+                    })
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let Some(key) = key else {
+            return Err(AvroBytesError::MissingPairField("key").into_de());
+        };
+        let Some(value) = value else {
+            return Err(AvroBytesError::MissingPairField("value").into_de());
+        };
+        Ok(ZeroizingPair { key, value })
+    }
+}
+
+struct HashMapZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for HashMapZeroizingBytesVisitor {
+    type Value = HashMap<Vec<u8>, Zeroizing<Vec<u8>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "an array of Pair records")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // growing the map only moves the buffer handles, never the secret bytes
+        let mut map = HashMap::with_capacity(cautious(seq.size_hint()));
+        let mut index = 0;
+        while let Some(ZeroizingPair { key, value }) = seq
+            .next_element::<ZeroizingPair>()
+            .map_err(|err| at_index(index, err))?
+        {
+            if map.contains_key(&key) {
+                return Err(at_index(index, AvroBytesError::DuplicateKey(key).into_de()));
+            }
+            map.insert(key, value);
+            index += 1;
+        }
+        Ok(map)
+    }
+}
+
+struct BTreeMapZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for BTreeMapZeroizingBytesVisitor {
+    type Value = BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "an array of Pair records")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut map = BTreeMap::new();
+        let mut index = 0;
+        while let Some(ZeroizingPair { key, value }) = seq
+            .next_element::<ZeroizingPair>()
+            .map_err(|err| at_index(index, err))?
+        {
+            if map.contains_key(&key) {
+                return Err(at_index(index, AvroBytesError::DuplicateKey(key).into_de()));
+            }
+            map.insert(key, value);
+            index += 1;
+        }
+        Ok(map)
+    }
+}
+
+struct OptionHashMapZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for OptionHashMapZeroizingBytesVisitor {
+    type Value = Option<HashMap<Vec<u8>, Zeroizing<Vec<u8>>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or an array of Pair records")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_hashmap_zeroizing_bytes(deserializer)?))
+    }
+}
+
+struct OptionBTreeMapZeroizingBytesVisitor;
+
+impl<'de> Visitor<'de> for OptionBTreeMapZeroizingBytesVisitor {
+    type Value = Option<BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or an array of Pair records")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_btreemap_zeroizing_bytes(deserializer)?))
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::Formatter;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use crate::avro_bytes::{
+    de::{cautious, map::Pair},
+    error::at_index,
+};
+
+pub(crate) type Tuple = (Vec<u8>, Vec<u8>);
+
+#[allow(unused)]
+pub fn deserialize_tuple<'de, D>(deserializer: D) -> Result<Tuple, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Pair { key, value } = Pair::deserialize(deserializer)?;
+    Ok((key.0, value.0))
+}
+
+#[allow(unused)]
+pub fn deserialize_option_tuple<'de, D>(deserializer: D) -> Result<Option<Tuple>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionTupleVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+struct OptionTupleVisitor;
+
+impl<'de> Visitor<'de> for OptionTupleVisitor {
+    type Value = Option<Tuple>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or a Pair record")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_tuple(deserializer)?))
+    }
+}
+
+#[allow(unused)]
+pub fn deserialize_list_tuple<'de, D>(deserializer: D) -> Result<Vec<Tuple>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = ListTupleVisitor;
+    deserializer.deserialize_seq(visitor)
+}
+
+struct ListTupleVisitor;
+
+impl<'de> Visitor<'de> for ListTupleVisitor {
+    type Value = Vec<Tuple>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "an array of Pair records")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut tuples = Vec::with_capacity(cautious(seq.size_hint()));
+        while let Some(Pair { key, value }) = seq
+            .next_element::<Pair>()
+            .map_err(|err| at_index(tuples.len(), err))?
+        {
+            tuples.push((key.0, value.0));
+        }
+        Ok(tuples)
+    }
+}
+
+#[allow(unused)]
+pub fn deserialize_option_list_tuple<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Tuple>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = OptionListTupleVisitor;
+    deserializer.deserialize_option(visitor)
+}
+
+struct OptionListTupleVisitor;
+
+impl<'de> Visitor<'de> for OptionListTupleVisitor {
+    type Value = Option<Vec<Tuple>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "null or an array of Pair records")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(deserialize_list_tuple(deserializer)?))
+    }
+}
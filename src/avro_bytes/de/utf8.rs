@@ -0,0 +1,242 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::de::Error;
+use serde::Deserializer;
+
+use crate::avro_bytes::{
+    de::{
+        bytes::{deserialize_bytes, deserialize_option_bytes},
+        list::{deserialize_list, deserialize_option_list},
+        tuple::{deserialize_list_tuple, deserialize_option_list_tuple, Tuple},
+    },
+    error::{at_index, at_key, Error as AvroBytesError},
+};
+
+/// Conversion of decoded bytes into a `String`, either strict or lossy.
+type Decode = fn(Vec<u8>) -> Result<String, AvroBytesError>;
+
+fn strict(bytes: Vec<u8>) -> Result<String, AvroBytesError> {
+    String::from_utf8(bytes).map_err(|err| AvroBytesError::InvalidUtf8 {
+        offset: err.utf8_error().valid_up_to(),
+    })
+}
+
+fn lossy(bytes: Vec<u8>) -> Result<String, AvroBytesError> {
+    // valid data is kept without copy
+    Ok(String::from_utf8(bytes)
+        .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned()))
+}
+
+fn string<E: Error>(bytes: Vec<u8>, decode: Decode) -> Result<String, E> {
+    decode(bytes).map_err(AvroBytesError::into_de)
+}
+
+fn list<E: Error>(items: Vec<Vec<u8>>, decode: Decode) -> Result<Vec<String>, E> {
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, bytes)| string(bytes, decode).map_err(|err| at_index(index, err)))
+        .collect()
+}
+
+/// Decodes a map entry, only strict decoding fails and then the key is the raw one.
+fn entry<E: Error>((key, value): Tuple, decode: Decode) -> Result<(String, String), E> {
+    let key = string(key, decode)?;
+    let value = string(value, decode).map_err(|err| at_key(key.as_bytes(), err))?;
+    Ok((key, value))
+}
+
+/// Distinct invalid keys can become equal once decoded lossily, which is refused.
+fn hashmap<E: Error>(entries: Vec<Tuple>, decode: Decode) -> Result<HashMap<String, String>, E> {
+    let mut map = HashMap::with_capacity(entries.len());
+    for (index, entry_bytes) in entries.into_iter().enumerate() {
+        let (key, value) = entry(entry_bytes, decode).map_err(|err| at_index(index, err))?;
+        if map.contains_key(&key) {
+            return Err(at_index(
+                index,
+                AvroBytesError::DuplicateKey(key.into_bytes()).into_de(),
+            ));
+        }
+        map.insert(key, value);
+    }
+    Ok(map)
+}
+
+fn btreemap<E: Error>(entries: Vec<Tuple>, decode: Decode) -> Result<BTreeMap<String, String>, E> {
+    let mut map = BTreeMap::new();
+    for (index, entry_bytes) in entries.into_iter().enumerate() {
+        let (key, value) = entry(entry_bytes, decode).map_err(|err| at_index(index, err))?;
+        if map.contains_key(&key) {
+            return Err(at_index(
+                index,
+                AvroBytesError::DuplicateKey(key.into_bytes()).into_de(),
+            ));
+        }
+        map.insert(key, value);
+    }
+    Ok(map)
+}
+
+#[allow(unused)]
+pub fn deserialize_strict_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    string(deserialize_bytes(deserializer)?, strict)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_strict_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_bytes(deserializer)?
+        .map(|bytes| string(bytes, strict))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_list_strict_string<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    list(deserialize_list(deserializer)?, strict)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_list_strict_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list(deserializer)?
+        .map(|items| list(items, strict))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_hashmap_strict_string<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    hashmap(deserialize_list_tuple(deserializer)?, strict)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_hashmap_strict_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list_tuple(deserializer)?
+        .map(|entries| hashmap(entries, strict))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_btreemap_strict_string<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    btreemap(deserialize_list_tuple(deserializer)?, strict)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_btreemap_strict_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<BTreeMap<String, String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list_tuple(deserializer)?
+        .map(|entries| btreemap(entries, strict))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_lossy_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    string(deserialize_bytes(deserializer)?, lossy)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_lossy_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_bytes(deserializer)?
+        .map(|bytes| string(bytes, lossy))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_list_lossy_string<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    list(deserialize_list(deserializer)?, lossy)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_list_lossy_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list(deserializer)?
+        .map(|items| list(items, lossy))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_hashmap_lossy_string<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    hashmap(deserialize_list_tuple(deserializer)?, lossy)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_hashmap_lossy_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list_tuple(deserializer)?
+        .map(|entries| hashmap(entries, lossy))
+        .transpose()
+}
+
+#[allow(unused)]
+pub fn deserialize_btreemap_lossy_string<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    btreemap(deserialize_list_tuple(deserializer)?, lossy)
+}
+
+#[allow(unused)]
+pub fn deserialize_option_btreemap_lossy_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<BTreeMap<String, String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_list_tuple(deserializer)?
+        .map(|entries| btreemap(entries, lossy))
+        .transpose()
+}
@@ -0,0 +1,70 @@
+use apache_avro::AvroSchema;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Serde format of the values embedded in byte fields.
+#[cfg(any(feature = "bincode", feature = "json", feature = "msgpack"))]
+pub(crate) trait Format {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String>;
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String>;
+}
+
+#[cfg(feature = "bincode")]
+pub(crate) struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl Format for Bincode {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+        bincode::serialize(value).map_err(|err| err.to_string())
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        bincode::deserialize(bytes).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(feature = "json")]
+pub(crate) struct Json;
+
+#[cfg(feature = "json")]
+impl Format for Json {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(value).map_err(|err| err.to_string())
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        serde_json::from_slice(bytes).map_err(|err| err.to_string())
+    }
+}
+
+/// Structs are written as maps, keeping field names.
+#[cfg(feature = "msgpack")]
+pub(crate) struct MessagePack;
+
+#[cfg(feature = "msgpack")]
+impl Format for MessagePack {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec_named(value).map_err(|err| err.to_string())
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        rmp_serde::from_slice(bytes).map_err(|err| err.to_string())
+    }
+}
+
+/// Avro datum written with the schema of `T`, without any header.
+pub(crate) struct Avro;
+
+impl Avro {
+    pub(crate) fn encode<T: Serialize + AvroSchema>(value: &T) -> Result<Vec<u8>, String> {
+        apache_avro::to_value(value)
+            .and_then(|value| apache_avro::to_avro_datum(&T::get_schema(), value))
+            .map_err(|err| err.to_string())
+    }
+
+    pub(crate) fn decode<T: DeserializeOwned + AvroSchema>(mut bytes: &[u8]) -> Result<T, String> {
+        apache_avro::from_avro_datum(&T::get_schema(), &mut bytes, None)
+            .and_then(|value| apache_avro::from_value(&value))
+            .map_err(|err| err.to_string())
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+
+/// Number of leading bytes of a key shown in error messages.
+const KEY_PREVIEW: usize = 16;
+
+/// Failures reported by the crate, converted into the serializer or deserializer error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The same key appears twice in a map.
+    DuplicateKey(Vec<u8>),
+    /// The decoded data is longer than allowed.
+    LengthLimit { length: usize, limit: usize },
+    /// The bytes are not valid UTF-8 starting at `offset`.
+    InvalidUtf8 { offset: usize },
+    /// The bytes do not have the size of the fixed type.
+    WrongFixedSize { expected: usize, actual: usize },
+    /// A map entry misses its `key` or `value` field.
+    MissingPairField(&'static str),
+    /// A legacy `array<int>` element does not fit in a `u8`.
+    InvalidByte { index: usize, value: i64 },
+    /// The header of a compressed field names a codec which is unknown or not enabled.
+    UnknownCodec(u8),
+    /// A compressed field is malformed.
+    Decompression(String),
+    /// A checksummed field is too short to hold its checksum.
+    MissingChecksum { length: usize },
+    /// The checksum stored with the bytes does not match them.
+    ChecksumMismatch { expected: u64, actual: u64 },
+    /// The length of packed numbers is not a multiple of the element size.
+    PackedLength { length: usize, element_size: usize },
+    /// A delta-encoded integer is malformed or overflows.
+    InvalidDelta { index: usize },
+    /// A value to delta-encode is smaller than the previous one.
+    UnsortedDelta { index: usize },
+    /// The bit length heading a bit set is malformed.
+    InvalidBitLength,
+    /// An embedded value cannot be decoded from its format.
+    Embedded(String),
+    /// An encrypted field is used outside of `encrypted::with_cipher`.
+    MissingCipher,
+    /// The field cipher failed.
+    Cipher(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DuplicateKey(key) => write!(f, "unexpected duplicate key: {}", Hex(key)),
+            Error::LengthLimit { length, limit } => {
+                write!(f, "length {length} exceeds the limit of {limit} bytes")
+            }
+            Error::InvalidUtf8 { offset } => write!(f, "invalid UTF-8 at byte offset {offset}"),
+            Error::WrongFixedSize { expected, actual } => {
+                write!(f, "expected {expected} bytes, got {actual}")
+            }
+            Error::MissingPairField(field) => write!(f, "missing pair field `{field}`"),
+            Error::InvalidByte { index, value } => {
+                write!(
+                    f,
+                    "invalid byte at index {index}: {value} is not in 0..=255"
+                )
+            }
+            Error::UnknownCodec(header) => {
+                write!(f, "unknown or disabled compression codec {header}")
+            }
+            Error::Decompression(reason) => write!(f, "decompression failed: {reason}"),
+            Error::MissingChecksum { length } => {
+                write!(f, "{length} bytes are too short to hold a checksum")
+            }
+            Error::PackedLength {
+                length,
+                element_size,
+            } => write!(
+                f,
+                "{length} bytes are not a multiple of the {element_size}-byte element size"
+            ),
+            Error::InvalidDelta { index } => write!(f, "invalid delta at index {index}"),
+            Error::UnsortedDelta { index } => {
+                write!(f, "value at index {index} is smaller than the previous one")
+            }
+            Error::InvalidBitLength => write!(f, "invalid bit set length"),
+            Error::Embedded(reason) => write!(f, "invalid embedded value: {reason}"),
+            Error::MissingCipher => write!(f, "no field cipher in scope"),
+            Error::Cipher(reason) => write!(f, "field cipher failed: {reason}"),
+            Error::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: stored {expected:#x}, computed {actual:#x}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    pub(crate) fn into_de<E: serde::de::Error>(self) -> E {
+        E::custom(self)
+    }
+}
+
+/// Prefixes a deserializer error with the list index it happened at.
+pub(crate) fn at_index<E: serde::de::Error>(index: usize, err: E) -> E {
+    E::custom(format_args!("at index {index}: {}", message(&err)))
+}
+
+/// Prefixes a deserializer error with the map key it happened at.
+pub(crate) fn at_key<E: serde::de::Error>(key: &[u8], err: E) -> E {
+    E::custom(format_args!("at key {}: {}", Hex(key), message(&err)))
+}
+
+/// Message of an error without the prefix `E::custom` adds, so that wrapping an
+/// error does not repeat it.
+fn message<E: serde::de::Error>(err: &E) -> String {
+    let prefix = E::custom("").to_string();
+    let message = err.to_string();
+    match message.strip_prefix(prefix.as_str()) {
+        Some(stripped) if !prefix.is_empty() => stripped.to_string(),
+        _ => message,
+    }
+}
+
+/// Hexadecimal rendering of a key, truncated to its first bytes.
+pub(crate) struct Hex<'a>(pub(crate) &'a [u8]);
+
+impl Display for Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0.iter().take(KEY_PREVIEW) {
+            write!(f, "{byte:02x}")?;
+        }
+        if self.0.len() > KEY_PREVIEW {
+            write!(f, "... ({} bytes)", self.0.len())?;
+        }
+        Ok(())
+    }
+}
@@ -1,2 +1,13 @@
+#[cfg(any(feature = "bitvec", feature = "fixedbitset"))]
+pub(crate) mod bits;
+#[cfg(any(feature = "crc32c", feature = "xxhash"))]
+pub mod checksum;
+pub mod cipher;
+#[cfg(any(feature = "deflate", feature = "snappy", feature = "zstd"))]
+pub mod compression;
 pub mod de;
+pub(crate) mod embedded;
+pub mod error;
+pub mod packed;
 pub mod ser;
+pub(crate) mod varint;
@@ -0,0 +1,139 @@
+use crate::avro_bytes::{error::Error as AvroBytesError, varint};
+
+/// Numeric type stored as fixed-width little-endian bytes by the `packed` modules.
+pub trait Packed: Copy {
+    /// Width of an element in bytes.
+    const SIZE: usize;
+
+    fn write_le(self, buffer: &mut Vec<u8>);
+
+    /// Reads an element from a slice of exactly `SIZE` bytes.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_packed {
+    ($($ty:ty),*) => {
+        $(
+            impl Packed for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn write_le(self, buffer: &mut Vec<u8>) {
+                    buffer.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut array = [0; std::mem::size_of::<$ty>()];
+                    array.copy_from_slice(bytes);
+                    <$ty>::from_le_bytes(array)
+                }
+            }
+        )*
+    };
+}
+
+impl_packed!(u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Integer type stored by `packed::delta` as its first value followed by the
+/// differences between consecutive values, all as unsigned LEB128 integers.
+pub trait Delta: Copy + Ord {
+    /// Maps the first value to an unsigned integer.
+    fn first(self) -> u64;
+
+    fn from_first(first: u64) -> Self;
+
+    /// Difference with a previous value, not greater than `self`.
+    fn delta(self, previous: Self) -> u64;
+
+    fn checked_add_delta(self, delta: u64) -> Option<Self>;
+}
+
+impl Delta for u64 {
+    fn first(self) -> u64 {
+        self
+    }
+
+    fn from_first(first: u64) -> Self {
+        first
+    }
+
+    fn delta(self, previous: Self) -> u64 {
+        self - previous
+    }
+
+    fn checked_add_delta(self, delta: u64) -> Option<Self> {
+        self.checked_add(delta)
+    }
+}
+
+impl Delta for i64 {
+    /// Zigzag encoding, keeping small negative values short.
+    fn first(self) -> u64 {
+        ((self << 1) ^ (self >> 63)) as u64
+    }
+
+    fn from_first(first: u64) -> Self {
+        (first >> 1) as i64 ^ -((first & 1) as i64)
+    }
+
+    fn delta(self, previous: Self) -> u64 {
+        self.abs_diff(previous)
+    }
+
+    fn checked_add_delta(self, delta: u64) -> Option<Self> {
+        self.checked_add_unsigned(delta)
+    }
+}
+
+pub(crate) fn pack<T: Packed>(values: &[T]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(values.len() * T::SIZE);
+    for value in values {
+        value.write_le(&mut buffer);
+    }
+    buffer
+}
+
+/// Reads the elements one by one, so that `bytes` needs no particular alignment.
+pub(crate) fn unpack<T: Packed>(bytes: &[u8]) -> Result<Vec<T>, AvroBytesError> {
+    if bytes.len() % T::SIZE != 0 {
+        return Err(AvroBytesError::PackedLength {
+            length: bytes.len(),
+            element_size: T::SIZE,
+        });
+    }
+    Ok(bytes.chunks_exact(T::SIZE).map(T::read_le).collect())
+}
+
+/// Encodes sorted `values`, refusing the first value smaller than its predecessor otherwise.
+pub(crate) fn pack_delta<T: Delta>(values: &[T]) -> Result<Vec<u8>, AvroBytesError> {
+    let mut buffer = Vec::with_capacity(values.len());
+    let mut previous = None;
+    for (index, &value) in values.iter().enumerate() {
+        let encoded = match previous {
+            None => value.first(),
+            Some(previous) if value < previous => {
+                return Err(AvroBytesError::UnsortedDelta { index })
+            }
+            Some(previous) => value.delta(previous),
+        };
+        varint::write(encoded, &mut buffer);
+        previous = Some(value);
+    }
+    Ok(buffer)
+}
+
+pub(crate) fn unpack_delta<T: Delta>(mut bytes: &[u8]) -> Result<Vec<T>, AvroBytesError> {
+    let mut values = Vec::<T>::new();
+    while !bytes.is_empty() {
+        let index = values.len();
+        let (encoded, rest) = varint::read(bytes).ok_or(AvroBytesError::InvalidDelta { index })?;
+        let value = match values.last() {
+            None => T::from_first(encoded),
+            Some(previous) => previous
+                .checked_add_delta(encoded)
+                .ok_or(AvroBytesError::InvalidDelta { index })?,
+        };
+        values.push(value);
+        bytes = rest;
+    }
+    Ok(values)
+}
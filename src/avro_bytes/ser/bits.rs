@@ -0,0 +1,52 @@
+use serde::Serializer;
+
+use crate::avro_bytes::ser::bytes::Bytes;
+
+#[cfg(feature = "bitvec")]
+use crate::avro_bytes::bits::pack_bitvec;
+#[cfg(feature = "bitvec")]
+use bitvec::vec::BitVec;
+
+#[cfg(feature = "fixedbitset")]
+use crate::avro_bytes::bits::pack_fixedbitset;
+#[cfg(feature = "fixedbitset")]
+use fixedbitset::FixedBitSet;
+
+fn serialize_option_packed<S: Serializer>(
+    packed: Option<Vec<u8>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match packed {
+        None => serializer.serialize_none(),
+        Some(packed) => serializer.serialize_some(&Bytes(&packed)),
+    }
+}
+
+#[cfg(feature = "bitvec")]
+pub fn serialize_bitvec<S: Serializer>(v: &BitVec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&pack_bitvec(v))
+}
+
+#[cfg(feature = "bitvec")]
+pub fn serialize_option_bitvec<S: Serializer>(
+    v: &Option<BitVec<u8>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_packed(v.as_ref().map(pack_bitvec), serializer)
+}
+
+#[cfg(feature = "fixedbitset")]
+pub fn serialize_fixedbitset<S: Serializer>(
+    v: &FixedBitSet,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&pack_fixedbitset(v))
+}
+
+#[cfg(feature = "fixedbitset")]
+pub fn serialize_option_fixedbitset<S: Serializer>(
+    v: &Option<FixedBitSet>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_packed(v.as_ref().map(pack_fixedbitset), serializer)
+}
@@ -1,12 +1,6 @@
-use serde::{Serialize, Serializer};
+use serde::Serializer;
 
-pub struct Bytes<'a>(pub(crate) &'a [u8]);
-
-impl Serialize for Bytes<'_> {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_bytes(self.0)
-    }
-}
+pub use crate::types::AvroByteSlice as Bytes;
 
 pub fn serialize_bytes<S: Serializer>(
     v: impl AsRef<[u8]>,
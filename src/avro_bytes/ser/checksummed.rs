@@ -0,0 +1,109 @@
+use serde::Serializer;
+
+use crate::avro_bytes::{
+    checksum::Checksum,
+    ser::{
+        bytes::{serialize_bytes, serialize_option_bytes},
+        list::{serialize_list_bytes, serialize_option_list_bytes},
+    },
+};
+
+fn append_list<T: AsRef<[u8]>>(checksum: Checksum, v: &[T]) -> Vec<Vec<u8>> {
+    v.iter().map(|x| checksum.append(x.as_ref())).collect()
+}
+
+fn serialize_checksummed<S: Serializer>(
+    checksum: Checksum,
+    v: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_bytes(checksum.append(v), serializer)
+}
+
+fn serialize_option_checksummed<S: Serializer>(
+    checksum: Checksum,
+    v: Option<&[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_bytes(&v.map(|bytes| checksum.append(bytes)), serializer)
+}
+
+fn serialize_list_checksummed<S: Serializer, T: AsRef<[u8]>>(
+    checksum: Checksum,
+    v: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_list_bytes(&append_list(checksum, v), serializer)
+}
+
+fn serialize_option_list_checksummed<S: Serializer, T: AsRef<[u8]>>(
+    checksum: Checksum,
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_list_bytes(&v.as_deref().map(|v| append_list(checksum, v)), serializer)
+}
+
+#[cfg(feature = "crc32c")]
+pub fn serialize_crc32c_bytes<S: Serializer>(
+    v: impl AsRef<[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_checksummed(Checksum::Crc32c, v.as_ref(), serializer)
+}
+
+#[cfg(feature = "crc32c")]
+pub fn serialize_option_crc32c_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_checksummed(Checksum::Crc32c, v.as_ref().map(AsRef::as_ref), serializer)
+}
+
+#[cfg(feature = "crc32c")]
+pub fn serialize_list_crc32c_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_list_checksummed(Checksum::Crc32c, v, serializer)
+}
+
+#[cfg(feature = "crc32c")]
+pub fn serialize_option_list_crc32c_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_list_checksummed(Checksum::Crc32c, v, serializer)
+}
+
+#[cfg(feature = "xxhash")]
+pub fn serialize_xxh3_bytes<S: Serializer>(
+    v: impl AsRef<[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_checksummed(Checksum::Xxh3, v.as_ref(), serializer)
+}
+
+#[cfg(feature = "xxhash")]
+pub fn serialize_option_xxh3_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_checksummed(Checksum::Xxh3, v.as_ref().map(AsRef::as_ref), serializer)
+}
+
+#[cfg(feature = "xxhash")]
+pub fn serialize_list_xxh3_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_list_checksummed(Checksum::Xxh3, v, serializer)
+}
+
+#[cfg(feature = "xxhash")]
+pub fn serialize_option_list_xxh3_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_list_checksummed(Checksum::Xxh3, v, serializer)
+}
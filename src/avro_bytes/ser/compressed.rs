@@ -0,0 +1,77 @@
+use serde::{ser::Error, Serializer};
+
+use crate::avro_bytes::{
+    compression::{compress, Codec},
+    ser::bytes::Bytes,
+};
+
+fn serialize_compressed<S: Serializer>(
+    codec: Codec,
+    v: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let compressed = compress(codec, v).map_err(S::Error::custom)?;
+    serializer.serialize_bytes(&compressed)
+}
+
+fn serialize_option_compressed<S: Serializer>(
+    codec: Codec,
+    v: Option<&[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(bytes) => {
+            let compressed = compress(codec, bytes).map_err(S::Error::custom)?;
+            serializer.serialize_some(&Bytes(&compressed))
+        }
+    }
+}
+
+#[cfg(feature = "deflate")]
+pub fn serialize_deflate_bytes<S: Serializer>(
+    v: impl AsRef<[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_compressed(Codec::Deflate, v.as_ref(), serializer)
+}
+
+#[cfg(feature = "deflate")]
+pub fn serialize_option_deflate_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_compressed(Codec::Deflate, v.as_ref().map(AsRef::as_ref), serializer)
+}
+
+#[cfg(feature = "snappy")]
+pub fn serialize_snappy_bytes<S: Serializer>(
+    v: impl AsRef<[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_compressed(Codec::Snappy, v.as_ref(), serializer)
+}
+
+#[cfg(feature = "snappy")]
+pub fn serialize_option_snappy_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_compressed(Codec::Snappy, v.as_ref().map(AsRef::as_ref), serializer)
+}
+
+#[cfg(feature = "zstd")]
+pub fn serialize_zstd_bytes<S: Serializer>(
+    v: impl AsRef<[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_compressed(Codec::Zstd, v.as_ref(), serializer)
+}
+
+#[cfg(feature = "zstd")]
+pub fn serialize_option_zstd_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_compressed(Codec::Zstd, v.as_ref().map(AsRef::as_ref), serializer)
+}
@@ -0,0 +1,92 @@
+use apache_avro::AvroSchema;
+use serde::{ser::Error, Serialize, Serializer};
+
+use crate::avro_bytes::{embedded::Avro, ser::bytes::Bytes};
+
+#[cfg(feature = "bincode")]
+use crate::avro_bytes::embedded::Bincode;
+#[cfg(any(feature = "bincode", feature = "json", feature = "msgpack"))]
+use crate::avro_bytes::embedded::Format;
+#[cfg(feature = "json")]
+use crate::avro_bytes::embedded::Json;
+#[cfg(feature = "msgpack")]
+use crate::avro_bytes::embedded::MessagePack;
+
+fn serialize_encoded<S: Serializer>(
+    encoded: Result<Vec<u8>, String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&encoded.map_err(S::Error::custom)?)
+}
+
+fn serialize_option_encoded<S: Serializer>(
+    encoded: Option<Result<Vec<u8>, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match encoded.transpose().map_err(S::Error::custom)? {
+        None => serializer.serialize_none(),
+        Some(bytes) => serializer.serialize_some(&Bytes(&bytes)),
+    }
+}
+
+#[cfg(feature = "bincode")]
+pub fn serialize_bincode<S: Serializer, T: Serialize>(
+    v: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_encoded(Bincode::encode(v), serializer)
+}
+
+#[cfg(feature = "bincode")]
+pub fn serialize_option_bincode<S: Serializer, T: Serialize>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_encoded(v.as_ref().map(Bincode::encode), serializer)
+}
+
+#[cfg(feature = "json")]
+pub fn serialize_json<S: Serializer, T: Serialize>(
+    v: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_encoded(Json::encode(v), serializer)
+}
+
+#[cfg(feature = "json")]
+pub fn serialize_option_json<S: Serializer, T: Serialize>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_encoded(v.as_ref().map(Json::encode), serializer)
+}
+
+#[cfg(feature = "msgpack")]
+pub fn serialize_msgpack<S: Serializer, T: Serialize>(
+    v: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_encoded(MessagePack::encode(v), serializer)
+}
+
+#[cfg(feature = "msgpack")]
+pub fn serialize_option_msgpack<S: Serializer, T: Serialize>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_encoded(v.as_ref().map(MessagePack::encode), serializer)
+}
+
+pub fn serialize_avro<S: Serializer, T: Serialize + AvroSchema>(
+    v: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_encoded(Avro::encode(v), serializer)
+}
+
+pub fn serialize_option_avro<S: Serializer, T: Serialize + AvroSchema>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_option_encoded(v.as_ref().map(Avro::encode), serializer)
+}
@@ -0,0 +1,50 @@
+use serde::{ser::Error, Serializer};
+
+use crate::avro_bytes::{
+    cipher::encrypt,
+    ser::{
+        bytes::{serialize_bytes, serialize_option_bytes},
+        list::{serialize_list_bytes, serialize_option_list_bytes},
+    },
+};
+
+fn encrypt_bytes<E: Error>(bytes: &[u8]) -> Result<Vec<u8>, E> {
+    encrypt(bytes).map_err(E::custom)
+}
+
+fn encrypt_list<E: Error, T: AsRef<[u8]>>(v: &[T]) -> Result<Vec<Vec<u8>>, E> {
+    v.iter().map(|x| encrypt_bytes(x.as_ref())).collect()
+}
+
+pub fn serialize_encrypted_bytes<S: Serializer>(
+    v: impl AsRef<[u8]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_bytes(encrypt_bytes::<S::Error>(v.as_ref())?, serializer)
+}
+
+pub fn serialize_option_encrypted_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let encrypted = v
+        .as_ref()
+        .map(|bytes| encrypt_bytes::<S::Error>(bytes.as_ref()))
+        .transpose()?;
+    serialize_option_bytes(&encrypted, serializer)
+}
+
+pub fn serialize_list_encrypted_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_list_bytes(&encrypt_list::<S::Error, T>(v)?, serializer)
+}
+
+pub fn serialize_option_list_encrypted_bytes<S: Serializer, T: AsRef<[u8]>>(
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let encrypted = v.as_deref().map(encrypt_list::<S::Error, T>).transpose()?;
+    serialize_option_list_bytes(&encrypted, serializer)
+}
@@ -1,4 +1,19 @@
+#[cfg(any(feature = "bitvec", feature = "fixedbitset"))]
+pub mod bits;
 pub mod bytes;
+#[cfg(any(feature = "crc32c", feature = "xxhash"))]
+pub mod checksummed;
+#[cfg(any(feature = "deflate", feature = "snappy", feature = "zstd"))]
+pub mod compressed;
+pub mod embedded;
+pub mod encrypted;
 pub mod list;
 pub mod map;
-mod pair;
+pub mod packed;
+pub(crate) mod pair;
+pub mod tuple;
+
+#[cfg(unix)]
+pub mod path;
+#[cfg(feature = "secret")]
+pub mod secret;
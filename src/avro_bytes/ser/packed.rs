@@ -0,0 +1,41 @@
+use serde::{ser::Error, Serializer};
+
+use crate::avro_bytes::{
+    packed::{pack, pack_delta, Delta, Packed},
+    ser::bytes::Bytes,
+};
+
+pub fn serialize_packed<S: Serializer, T: Packed>(
+    v: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&pack(v))
+}
+
+pub fn serialize_option_packed<S: Serializer, T: Packed>(
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(values) => serializer.serialize_some(&Bytes(&pack(values))),
+    }
+}
+
+fn delta<E: Error, T: Delta>(v: &[T]) -> Result<Vec<u8>, E> {
+    pack_delta(v).map_err(E::custom)
+}
+
+pub fn serialize_delta<S: Serializer, T: Delta>(v: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&delta::<S::Error, T>(v)?)
+}
+
+pub fn serialize_option_delta<S: Serializer, T: Delta>(
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(values) => serializer.serialize_some(&Bytes(&delta::<S::Error, T>(values)?)),
+    }
+}
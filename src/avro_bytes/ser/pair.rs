@@ -2,6 +2,10 @@ use crate::avro_bytes::ser::bytes::Bytes;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 
+pub(crate) const PAIR: &str = "Pair";
+pub(crate) const KEY: &str = "key";
+pub(crate) const VALUE: &str = "value";
+
 #[derive(Debug)]
 pub(crate) struct Pair<'a> {
     pub(crate) key: &'a [u8],
@@ -13,9 +17,9 @@ impl Serialize for Pair<'_> {
     where
         S: Serializer,
     {
-        let mut pair = serializer.serialize_struct("Pair", 2)?;
-        pair.serialize_field("key", &Bytes(self.key))?;
-        pair.serialize_field("value", &Bytes(self.value))?;
+        let mut pair = serializer.serialize_struct(PAIR, 2)?;
+        pair.serialize_field(KEY, &Bytes(self.key))?;
+        pair.serialize_field(VALUE, &Bytes(self.value))?;
         pair.end()
     }
 }
@@ -0,0 +1,47 @@
+use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+use serde::{ser::SerializeSeq, Serializer};
+
+use crate::avro_bytes::ser::bytes::Bytes;
+
+pub fn serialize_path<S: Serializer>(
+    v: impl AsRef<OsStr>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(v.as_ref().as_bytes())
+}
+
+pub fn serialize_option_path<S: Serializer, T: AsRef<OsStr>>(
+    v: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(path) => serializer.serialize_some(&Bytes(path.as_ref().as_bytes())),
+    }
+}
+
+pub fn serialize_list_path<S: Serializer, T: AsRef<OsStr>>(
+    v: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(v.len()))?;
+    for x in v {
+        seq.serialize_element(&Bytes(x.as_ref().as_bytes()))?;
+    }
+    seq.end()
+}
+
+pub fn serialize_option_list_path<S: Serializer, T: AsRef<OsStr>>(
+    v: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(v) => serializer.serialize_some(
+            &v.iter()
+                .map(|path| Bytes(path.as_ref().as_bytes()))
+                .collect::<Vec<_>>(),
+        ),
+    }
+}
@@ -0,0 +1,21 @@
+use ::secrecy::{ExposeSecret, SecretVec};
+use serde::Serializer;
+
+use crate::avro_bytes::ser::bytes::Bytes;
+
+pub fn serialize_secret_bytes<S: Serializer>(
+    v: &SecretVec<u8>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(v.expose_secret())
+}
+
+pub fn serialize_option_secret_bytes<S: Serializer>(
+    v: &Option<SecretVec<u8>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(secret) => serializer.serialize_some(&Bytes(secret.expose_secret())),
+    }
+}
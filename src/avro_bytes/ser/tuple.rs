@@ -0,0 +1,48 @@
+use serde::{ser::SerializeSeq, Serialize, Serializer};
+
+use crate::avro_bytes::ser::pair::Pair;
+
+fn pair<K: AsRef<[u8]>, V: AsRef<[u8]>>((key, value): &(K, V)) -> Pair<'_> {
+    Pair {
+        key: key.as_ref(),
+        value: value.as_ref(),
+    }
+}
+
+pub fn serialize_tuple<S: Serializer, K: AsRef<[u8]>, V: AsRef<[u8]>>(
+    v: &(K, V),
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    pair(v).serialize(serializer)
+}
+
+pub fn serialize_option_tuple<S: Serializer, K: AsRef<[u8]>, V: AsRef<[u8]>>(
+    v: &Option<(K, V)>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(v) => serializer.serialize_some(&pair(v)),
+    }
+}
+
+pub fn serialize_list_tuple<S: Serializer, K: AsRef<[u8]>, V: AsRef<[u8]>>(
+    v: &[(K, V)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(v.len()))?;
+    for x in v {
+        seq.serialize_element(&pair(x))?;
+    }
+    seq.end()
+}
+
+pub fn serialize_option_list_tuple<S: Serializer, K: AsRef<[u8]>, V: AsRef<[u8]>>(
+    v: &Option<Vec<(K, V)>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match v {
+        None => serializer.serialize_none(),
+        Some(v) => serializer.serialize_some(&v.iter().map(pair).collect::<Vec<_>>()),
+    }
+}
@@ -0,0 +1,53 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    process::ExitCode,
+};
+
+use apache_avro::Codec;
+use serde_avro_bytes::migrate::migrate;
+
+static USAGE: &str = "usage: avro-bytes-migrate [--deflate] <input.avro> <output.avro> <field>...";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let codec = match args.iter().position(|arg| arg == "--deflate") {
+        Some(position) => {
+            args.remove(position);
+            Codec::Deflate
+        }
+        None => Codec::Null,
+    };
+
+    let [input, output, fields @ ..] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    if fields.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    }
+    let fields = fields.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let files = File::open(input)
+        .and_then(|input| Ok((input, File::create(output)?)))
+        .map_err(|err| err.to_string());
+    let result = files.and_then(|(input, file)| {
+        migrate(BufReader::new(input), BufWriter::new(file), &fields, codec).map_err(|err| {
+            // nothing was written to the output, which is not left behind empty
+            let _ = std::fs::remove_file(output);
+            err.to_string()
+        })
+    });
+
+    match result {
+        Ok(count) => {
+            println!("{count} records migrated");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use apache_avro::{AvroSchema, Schema};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// First byte of every record framed with the Confluent wire format.
+pub const MAGIC_BYTE: u8 = 0;
+
+/// Size of the magic byte followed by the big-endian schema id.
+pub const HEADER_SIZE: usize = 5;
+
+#[derive(Debug)]
+pub enum ConfluentError {
+    Avro(apache_avro::Error),
+    /// The message is shorter than the framing header.
+    Truncated(usize),
+    InvalidMagicByte(u8),
+    /// No schema is registered under this id.
+    UnknownSchema(u32),
+    /// Failure reported by a `SchemaResolver` implementation.
+    Registry(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Display for ConfluentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfluentError::Avro(err) => write!(f, "avro error: {err}"),
+            ConfluentError::Truncated(length) => {
+                write!(
+                    f,
+                    "message of {length} bytes is shorter than the framing header"
+                )
+            }
+            ConfluentError::InvalidMagicByte(byte) => {
+                write!(
+                    f,
+                    "invalid magic byte {byte:#04x}, expected {MAGIC_BYTE:#04x}"
+                )
+            }
+            ConfluentError::UnknownSchema(id) => write!(f, "unknown schema id {id}"),
+            ConfluentError::Registry(err) => write!(f, "schema registry error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfluentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfluentError::Avro(err) => Some(err),
+            ConfluentError::Registry(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<apache_avro::Error> for ConfluentError {
+    fn from(err: apache_avro::Error) -> Self {
+        ConfluentError::Avro(err)
+    }
+}
+
+/// Source of the schemas referenced by framed records.
+pub trait SchemaResolver {
+    /// Returns the schema registered under `id`.
+    fn resolve(&self, id: u32) -> Result<Schema, ConfluentError>;
+
+    /// Registers `schema` under `subject` and returns its id.
+    fn register(&mut self, subject: &str, schema: &Schema) -> Result<u32, ConfluentError>;
+}
+
+/// Registry kept in memory, meant for tests and local tooling.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryRegistry {
+    schemas: Vec<Schema>,
+    subjects: HashMap<String, Vec<u32>>,
+}
+
+impl InMemoryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of the latest schema registered under `subject`.
+    pub fn latest(&self, subject: &str) -> Option<u32> {
+        self.subjects.get(subject)?.last().copied()
+    }
+}
+
+impl SchemaResolver for InMemoryRegistry {
+    fn resolve(&self, id: u32) -> Result<Schema, ConfluentError> {
+        (id as usize)
+            .checked_sub(1)
+            .and_then(|index| self.schemas.get(index))
+            .cloned()
+            .ok_or(ConfluentError::UnknownSchema(id))
+    }
+
+    fn register(&mut self, subject: &str, schema: &Schema) -> Result<u32, ConfluentError> {
+        let canonical_form = schema.canonical_form();
+        let id = match self
+            .schemas
+            .iter()
+            .position(|known| known.canonical_form() == canonical_form)
+        {
+            Some(index) => index as u32 + 1,
+            None => {
+                self.schemas.push(schema.clone());
+                self.schemas.len() as u32
+            }
+        };
+
+        let versions = self.subjects.entry(subject.to_string()).or_default();
+        if !versions.contains(&id) {
+            versions.push(id);
+        }
+        Ok(id)
+    }
+}
+
+/// Registers the schema of `T` under `subject`.
+pub fn register<T: AvroSchema>(
+    registry: &mut impl SchemaResolver,
+    subject: &str,
+) -> Result<u32, ConfluentError> {
+    registry.register(subject, &T::get_schema())
+}
+
+/// Encodes `record` with `schema` behind the framing header of schema `id`.
+pub fn encode<T: Serialize>(
+    id: u32,
+    schema: &Schema,
+    record: &T,
+) -> Result<Vec<u8>, ConfluentError> {
+    let value = apache_avro::to_value(record)?;
+    let datum = apache_avro::to_avro_datum(schema, value)?;
+
+    let mut message = Vec::with_capacity(HEADER_SIZE + datum.len());
+    message.push(MAGIC_BYTE);
+    message.extend_from_slice(&id.to_be_bytes());
+    message.extend_from_slice(&datum);
+    Ok(message)
+}
+
+/// Splits a framed message into its schema id and Avro body.
+pub fn split(message: &[u8]) -> Result<(u32, &[u8]), ConfluentError> {
+    if message.len() < HEADER_SIZE {
+        return Err(ConfluentError::Truncated(message.len()));
+    }
+    let (header, body) = message.split_at(HEADER_SIZE);
+    if header[0] != MAGIC_BYTE {
+        return Err(ConfluentError::InvalidMagicByte(header[0]));
+    }
+    let id = u32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+    Ok((id, body))
+}
+
+/// Decodes a framed message with the writer schema served by `resolver`.
+pub fn decode<T: DeserializeOwned>(
+    resolver: &impl SchemaResolver,
+    message: &[u8],
+) -> Result<T, ConfluentError> {
+    decode_with_reader_schema(resolver, None, message)
+}
+
+/// Decodes a framed message, resolving its writer schema against `reader_schema`.
+pub fn decode_with_reader_schema<T: DeserializeOwned>(
+    resolver: &impl SchemaResolver,
+    reader_schema: Option<&Schema>,
+    message: &[u8],
+) -> Result<T, ConfluentError> {
+    let (id, mut body) = split(message)?;
+    let writer_schema = resolver.resolve(id)?;
+    let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+    Ok(apache_avro::from_value(&value)?)
+}
@@ -0,0 +1,127 @@
+use std::{collections::HashMap, fmt::Formatter};
+
+use apache_avro::{types::Value, Schema};
+use serde::{
+    de::{DeserializeOwned, Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::single_object::{fingerprint, split, SchemaTable, SingleObjectError, MARKER};
+
+/// Schemas of the datums carried by envelopes, indexed by fingerprint.
+pub trait FingerprintRegistry {
+    fn schema(&self, fingerprint: u64) -> Option<&Schema>;
+}
+
+impl FingerprintRegistry for SchemaTable {
+    fn schema(&self, fingerprint: u64) -> Option<&Schema> {
+        self.get(fingerprint)
+    }
+}
+
+impl FingerprintRegistry for HashMap<u64, Schema> {
+    fn schema(&self, fingerprint: u64) -> Option<&Schema> {
+        self.get(&fingerprint)
+    }
+}
+
+/// An Avro datum tagged with the fingerprint of its schema, so that a record can
+/// carry values of heterogeneous types.
+///
+/// It is stored in a `bytes` field using the single-object encoding, and is
+/// decoded with a schema looked up by fingerprint in a `FingerprintRegistry`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroEnvelope {
+    fingerprint: u64,
+    datum: Vec<u8>,
+}
+
+impl AvroEnvelope {
+    pub fn new(fingerprint: u64, datum: Vec<u8>) -> Self {
+        AvroEnvelope { fingerprint, datum }
+    }
+
+    /// Encodes `value` with `schema`.
+    pub fn encode<T: Serialize>(schema: &Schema, value: &T) -> Result<Self, SingleObjectError> {
+        Self::encode_value(schema, apache_avro::to_value(value)?)
+    }
+
+    pub fn encode_value(schema: &Schema, value: Value) -> Result<Self, SingleObjectError> {
+        let datum = apache_avro::to_avro_datum(schema, value)?;
+        Ok(AvroEnvelope::new(fingerprint(schema), datum))
+    }
+
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    pub fn datum(&self) -> &[u8] {
+        &self.datum
+    }
+
+    /// Decodes the datum with the schema of `registry` matching its fingerprint.
+    pub fn decode<T: DeserializeOwned>(
+        &self,
+        registry: &impl FingerprintRegistry,
+    ) -> Result<T, SingleObjectError> {
+        Ok(apache_avro::from_value(&self.decode_value(registry)?)?)
+    }
+
+    pub fn decode_value(
+        &self,
+        registry: &impl FingerprintRegistry,
+    ) -> Result<Value, SingleObjectError> {
+        let schema = registry
+            .schema(self.fingerprint)
+            .ok_or(SingleObjectError::UnknownFingerprint(self.fingerprint))?;
+        Ok(apache_avro::from_avro_datum(
+            schema,
+            &mut self.datum.as_slice(),
+            None,
+        )?)
+    }
+}
+
+/// Schema of an envelope field.
+pub fn schema() -> Schema {
+    crate::schema::bytes()
+}
+
+/// Schema of an optional envelope field.
+pub fn option_schema() -> Schema {
+    crate::schema::option_bytes()
+}
+
+impl Serialize for AvroEnvelope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut message = Vec::with_capacity(MARKER.len() + 8 + self.datum.len());
+        message.extend_from_slice(&MARKER);
+        message.extend_from_slice(&self.fingerprint.to_le_bytes());
+        message.extend_from_slice(&self.datum);
+        serializer.serialize_bytes(&message)
+    }
+}
+
+impl<'de> Deserialize<'de> for AvroEnvelope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(AvroEnvelopeVisitor)
+    }
+}
+
+struct AvroEnvelopeVisitor;
+
+impl<'de> Visitor<'de> for AvroEnvelopeVisitor {
+    type Value = AvroEnvelope;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "bytes holding a single-object encoded datum")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let (fingerprint, datum) = split(v).map_err(E::custom)?;
+        Ok(AvroEnvelope::new(fingerprint, datum.to_vec()))
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    io::{Read, Write},
+    marker::PhantomData,
+};
+
+use apache_avro::{AvroSchema, Codec, Reader, Schema, Writer};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Reads and writes Avro object container files of `T` records.
+///
+/// Records go through `apache_avro::to_value` and `apache_avro::from_value`,
+/// so fields annotated with this crate's modules are stored as `bytes`.
+#[derive(Debug, Clone)]
+pub struct AvroFile<T> {
+    schema: Schema,
+    codec: Codec,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T> AvroFile<T> {
+    pub fn new(schema: Schema) -> Self {
+        AvroFile {
+            schema,
+            codec: Codec::Null,
+            _record: PhantomData,
+        }
+    }
+
+    /// Selects the block codec used when writing, `Codec::Null` by default.
+    pub fn with_codec(mut self, codec: Codec) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    pub fn codec(&self) -> Codec {
+        self.codec
+    }
+
+    pub fn writer<W: Write>(&self, writer: W) -> AvroFileWriter<'_, T, W> {
+        AvroFileWriter {
+            writer: Writer::with_codec(&self.schema, writer, self.codec),
+            _record: PhantomData,
+        }
+    }
+
+    /// Opens a file, resolving its writer schema against the schema of `T`.
+    pub fn reader<R: Read>(
+        &self,
+        reader: R,
+    ) -> Result<AvroFileReader<'_, T, R>, apache_avro::Error> {
+        Ok(AvroFileReader {
+            reader: Reader::with_schema(&self.schema, reader)?,
+            _record: PhantomData,
+        })
+    }
+
+    /// Writes all the records to `writer` and returns it once flushed.
+    pub fn write_all<'a, W: Write>(
+        &self,
+        writer: W,
+        records: impl IntoIterator<Item = &'a T>,
+    ) -> Result<W, apache_avro::Error>
+    where
+        T: Serialize + 'a,
+    {
+        let mut writer = self.writer(writer);
+        for record in records {
+            writer.append(record)?;
+        }
+        writer.into_inner()
+    }
+
+    /// Reads all the records from `reader`.
+    pub fn read_all<R: Read>(&self, reader: R) -> Result<Vec<T>, apache_avro::Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.reader(reader)?.collect()
+    }
+}
+
+impl<T: AvroSchema> Default for AvroFile<T> {
+    fn default() -> Self {
+        AvroFile::new(T::get_schema())
+    }
+}
+
+pub struct AvroFileWriter<'a, T, W: Write> {
+    writer: Writer<'a, W>,
+    _record: PhantomData<fn(&T)>,
+}
+
+impl<T: Serialize, W: Write> AvroFileWriter<'_, T, W> {
+    /// Appends a record, returning the number of bytes written, which is 0 while the block is buffered.
+    pub fn append(&mut self, record: &T) -> Result<usize, apache_avro::Error> {
+        self.writer.append(apache_avro::to_value(record)?)
+    }
+
+    pub fn flush(&mut self) -> Result<usize, apache_avro::Error> {
+        self.writer.flush()
+    }
+
+    pub fn into_inner(self) -> Result<W, apache_avro::Error> {
+        self.writer.into_inner()
+    }
+}
+
+pub struct AvroFileReader<'a, T, R> {
+    reader: Reader<'a, R>,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T, R: Read> AvroFileReader<'_, T, R> {
+    pub fn writer_schema(&self) -> &Schema {
+        self.reader.writer_schema()
+    }
+}
+
+impl<T: DeserializeOwned, R: Read> Iterator for AvroFileReader<'_, T, R> {
+    type Item = Result<T, apache_avro::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.reader.next()?;
+        Some(value.and_then(|value| apache_avro::from_value(&value)))
+    }
+}
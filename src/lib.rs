@@ -1,10 +1,28 @@
+pub mod audit;
 mod avro_bytes;
+pub mod confluent;
+pub mod envelope;
+pub mod file;
+pub mod migrate;
+pub mod schema;
+pub mod single_object;
+mod types;
+pub mod union;
+pub mod value;
 
 use avro_bytes::{de, ser};
 
+pub use avro_bytes::error::Error;
+pub use types::{AvroByteSlice, AvroBytes, AvroBytesMap};
+
+#[cfg(feature = "derive")]
+pub use serde_avro_bytes_derive::avro_bytes;
+
 pub mod bytes {
     pub use super::*;
 
+    #[allow(unused)]
+    pub use crate::schema::bytes as schema;
     #[allow(unused)]
     pub use de::bytes::deserialize_bytes as deserialize;
     #[allow(unused)]
@@ -13,15 +31,43 @@ pub mod bytes {
     pub mod option {
         pub use super::*;
         #[allow(unused)]
+        pub use crate::schema::option_bytes as schema;
+        #[allow(unused)]
         pub use de::bytes::deserialize_option_bytes as deserialize;
         #[allow(unused)]
         pub use ser::bytes::serialize_option_bytes as serialize;
     }
+
+    /// Legacy `array<int>` fields, refusing elements not fitting in a `u8`.
+    ///
+    /// apache-avro hands no `bytes` value to this decoder: it reads legacy data
+    /// only, and fields must switch to `bytes` once their data is migrated.
+    pub mod strict {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::bytes::deserialize_strict_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::bytes::serialize_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::bytes::deserialize_option_strict_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::bytes::serialize_option_bytes as serialize;
+        }
+    }
 }
 
 pub mod hashmap {
     pub use super::*;
     #[allow(unused)]
+    pub use crate::schema::map as schema;
+    #[allow(unused)]
     pub use de::map::deserialize_hashmap as deserialize;
     #[allow(unused)]
     pub use ser::map::serialize_hashmap as serialize;
@@ -29,6 +75,8 @@ pub mod hashmap {
     pub mod option {
         pub use super::*;
         #[allow(unused)]
+        pub use crate::schema::option_map as schema;
+        #[allow(unused)]
         pub use de::map::deserialize_option_hashmap as deserialize;
         #[allow(unused)]
         pub use ser::map::serialize_option_hashmap as serialize;
@@ -38,6 +86,8 @@ pub mod hashmap {
 pub mod btreemap {
     pub use super::*;
     #[allow(unused)]
+    pub use crate::schema::map as schema;
+    #[allow(unused)]
     pub use de::map::deserialize_btreemap as deserialize;
     #[allow(unused)]
     pub use ser::map::serialize_btreemap as serialize;
@@ -45,6 +95,8 @@ pub mod btreemap {
     pub mod option {
         pub use super::*;
         #[allow(unused)]
+        pub use crate::schema::option_map as schema;
+        #[allow(unused)]
         pub use de::map::deserialize_option_btreemap as deserialize;
         #[allow(unused)]
         pub use ser::map::serialize_option_btreemap as serialize;
@@ -54,6 +106,8 @@ pub mod btreemap {
 pub mod list {
     pub use super::*;
     #[allow(unused)]
+    pub use crate::schema::list as schema;
+    #[allow(unused)]
     pub use de::list::deserialize_list as deserialize;
     #[allow(unused)]
     pub use ser::list::serialize_list_bytes as serialize;
@@ -61,82 +115,846 @@ pub mod list {
     pub mod option {
         pub use super::*;
         #[allow(unused)]
+        pub use crate::schema::option_list as schema;
+        #[allow(unused)]
         pub use de::list::deserialize_option_list as deserialize;
         #[allow(unused)]
         pub use ser::list::serialize_option_list_bytes as serialize;
     }
 }
 
-pub mod extra {
+pub mod tuple {
     pub use super::*;
+    #[allow(unused)]
+    pub use crate::schema::pair as schema;
+    #[allow(unused)]
+    pub use de::tuple::deserialize_tuple as deserialize;
+    #[allow(unused)]
+    pub use ser::tuple::serialize_tuple as serialize;
 
-    #[cfg(feature = "bstr")]
-    pub mod bstr {
+    pub mod option {
         pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::option_pair as schema;
+        #[allow(unused)]
+        pub use de::tuple::deserialize_option_tuple as deserialize;
+        #[allow(unused)]
+        pub use ser::tuple::serialize_option_tuple as serialize;
+    }
 
-        pub use de::bstr::deserialize;
-        pub use ser::bytes::serialize_bytes as serialize;
+    pub mod list {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::map as schema;
+        #[allow(unused)]
+        pub use de::tuple::deserialize_list_tuple as deserialize;
+        #[allow(unused)]
+        pub use ser::tuple::serialize_list_tuple as serialize;
 
         pub mod option {
             pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_map as schema;
+            #[allow(unused)]
+            pub use de::tuple::deserialize_option_list_tuple as deserialize;
+            #[allow(unused)]
+            pub use ser::tuple::serialize_option_list_tuple as serialize;
+        }
+    }
+}
 
+/// Byte fields followed by a checksum, verified and removed when decoding.
+#[cfg(any(feature = "crc32c", feature = "xxhash"))]
+pub mod checksummed {
+    pub use super::*;
+
+    /// CRC-32C, stored on 4 bytes.
+    #[cfg(feature = "crc32c")]
+    pub mod crc32c {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::checksummed::deserialize_crc32c_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::checksummed::serialize_crc32c_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
             #[allow(unused)]
-            pub use de::bstr::deserialize_option as deserialize;
+            pub use crate::schema::option_bytes as schema;
             #[allow(unused)]
-            pub use ser::bytes::serialize_option_bytes as serialize;
+            pub use de::checksummed::deserialize_option_crc32c_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::checksummed::serialize_option_crc32c_bytes as serialize;
         }
 
         pub mod list {
             pub use super::*;
-
             #[allow(unused)]
-            pub use de::bstr::deserialize_list as deserialize;
+            pub use crate::schema::list as schema;
             #[allow(unused)]
-            pub use ser::list::serialize_list_bytes as serialize;
+            pub use de::checksummed::deserialize_list_crc32c_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::checksummed::serialize_list_crc32c_bytes as serialize;
 
             pub mod option {
                 pub use super::*;
-
                 #[allow(unused)]
-                pub use de::bstr::deserialize_option_list as deserialize;
+                pub use crate::schema::option_list as schema;
                 #[allow(unused)]
-                pub use ser::list::serialize_option_list_bytes as serialize;
+                pub use de::checksummed::deserialize_option_list_crc32c_bytes as deserialize;
+                #[allow(unused)]
+                pub use ser::checksummed::serialize_option_list_crc32c_bytes as serialize;
             }
         }
+    }
 
-        pub mod hashmap {
+    /// 64 bits XXH3, stored on 8 bytes.
+    #[cfg(feature = "xxhash")]
+    pub mod xxh3 {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::checksummed::deserialize_xxh3_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::checksummed::serialize_xxh3_bytes as serialize;
+
+        pub mod option {
             pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::checksummed::deserialize_option_xxh3_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::checksummed::serialize_option_xxh3_bytes as serialize;
+        }
 
+        pub mod list {
+            pub use super::*;
             #[allow(unused)]
-            pub use de::bstr::deserialize_hashmap as deserialize;
+            pub use crate::schema::list as schema;
             #[allow(unused)]
-            pub use ser::map::serialize_hashmap as serialize;
+            pub use de::checksummed::deserialize_list_xxh3_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::checksummed::serialize_list_xxh3_bytes as serialize;
 
             pub mod option {
                 pub use super::*;
                 #[allow(unused)]
-                pub use de::bstr::deserialize_option_hashmap as deserialize;
+                pub use crate::schema::option_list as schema;
                 #[allow(unused)]
-                pub use ser::map::serialize_option_hashmap as serialize;
+                pub use de::checksummed::deserialize_option_list_xxh3_bytes as deserialize;
+                #[allow(unused)]
+                pub use ser::checksummed::serialize_option_list_xxh3_bytes as serialize;
             }
         }
+    }
+}
 
-        pub mod btreemap {
+/// Byte fields compressed on their own, behind a header naming the codec and the
+/// decompressed length. Any enabled codec is accepted when decoding.
+#[cfg(any(feature = "deflate", feature = "snappy", feature = "zstd"))]
+pub mod compressed {
+    pub use super::*;
+    pub use crate::avro_bytes::compression::{with_limit, DEFAULT_LIMIT};
+
+    #[cfg(feature = "deflate")]
+    pub mod deflate {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::compressed::deserialize_compressed_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::compressed::serialize_deflate_bytes as serialize;
+
+        pub mod option {
             pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::compressed::deserialize_option_compressed_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::compressed::serialize_option_deflate_bytes as serialize;
+        }
+    }
 
+    #[cfg(feature = "snappy")]
+    pub mod snappy {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::compressed::deserialize_compressed_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::compressed::serialize_snappy_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
             #[allow(unused)]
-            pub use de::bstr::deserialize_btreemap as deserialize;
+            pub use crate::schema::option_bytes as schema;
             #[allow(unused)]
-            pub use ser::map::serialize_btreemap as serialize;
+            pub use de::compressed::deserialize_option_compressed_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::compressed::serialize_option_snappy_bytes as serialize;
+        }
+    }
 
-            pub mod option {
-                pub use super::*;
+    #[cfg(feature = "zstd")]
+    pub mod zstd {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::compressed::deserialize_compressed_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::compressed::serialize_zstd_bytes as serialize;
 
-                #[allow(unused)]
-                pub use de::bstr::deserialize_option_btreemap as deserialize;
-                #[allow(unused)]
-                pub use ser::map::serialize_option_btreemap as serialize;
-            }
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::compressed::deserialize_option_compressed_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::compressed::serialize_option_zstd_bytes as serialize;
+        }
+    }
+}
+
+/// Values of any serializable type embedded as bytes encoded with another format.
+pub mod embedded {
+    pub use super::*;
+
+    /// Avro datum written with the schema of the value type, given by `AvroSchema`.
+    pub mod avro {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::embedded::deserialize_avro as deserialize;
+        #[allow(unused)]
+        pub use ser::embedded::serialize_avro as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::embedded::deserialize_option_avro as deserialize;
+            #[allow(unused)]
+            pub use ser::embedded::serialize_option_avro as serialize;
+        }
+    }
+
+    /// bincode.
+    #[cfg(feature = "bincode")]
+    pub mod bincode {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::embedded::deserialize_bincode as deserialize;
+        #[allow(unused)]
+        pub use ser::embedded::serialize_bincode as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::embedded::deserialize_option_bincode as deserialize;
+            #[allow(unused)]
+            pub use ser::embedded::serialize_option_bincode as serialize;
+        }
+    }
+
+    /// JSON.
+    #[cfg(feature = "json")]
+    pub mod json {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::embedded::deserialize_json as deserialize;
+        #[allow(unused)]
+        pub use ser::embedded::serialize_json as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::embedded::deserialize_option_json as deserialize;
+            #[allow(unused)]
+            pub use ser::embedded::serialize_option_json as serialize;
+        }
+    }
+
+    /// MessagePack, structs being written as maps.
+    #[cfg(feature = "msgpack")]
+    pub mod msgpack {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::embedded::deserialize_msgpack as deserialize;
+        #[allow(unused)]
+        pub use ser::embedded::serialize_msgpack as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::embedded::deserialize_option_msgpack as deserialize;
+            #[allow(unused)]
+            pub use ser::embedded::serialize_option_msgpack as serialize;
+        }
+    }
+}
+
+/// Byte fields encrypted with the cipher of the enclosing `with_cipher` call.
+pub mod encrypted {
+    pub use super::*;
+    #[cfg(feature = "aes-gcm")]
+    pub use crate::avro_bytes::cipher::Aes256GcmCipher;
+    pub use crate::avro_bytes::cipher::{
+        with_associated_data, with_cipher, CipherError, FieldCipher, NoopCipher,
+    };
+    #[allow(unused)]
+    pub use crate::schema::bytes as schema;
+    #[allow(unused)]
+    pub use de::encrypted::deserialize_encrypted_bytes as deserialize;
+    #[allow(unused)]
+    pub use ser::encrypted::serialize_encrypted_bytes as serialize;
+
+    pub mod option {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::option_bytes as schema;
+        #[allow(unused)]
+        pub use de::encrypted::deserialize_option_encrypted_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::encrypted::serialize_option_encrypted_bytes as serialize;
+    }
+
+    pub mod list {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::list as schema;
+        #[allow(unused)]
+        pub use de::encrypted::deserialize_list_encrypted_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::encrypted::serialize_list_encrypted_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_list as schema;
+            #[allow(unused)]
+            pub use de::encrypted::deserialize_option_list_encrypted_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::encrypted::serialize_option_list_encrypted_bytes as serialize;
+        }
+    }
+}
+
+/// `Vec` of numbers stored as a single `bytes` value of fixed-width little-endian elements.
+pub mod packed {
+    pub use super::*;
+    pub use crate::avro_bytes::packed::Packed;
+    #[allow(unused)]
+    pub use crate::schema::bytes as schema;
+    #[allow(unused)]
+    pub use de::packed::deserialize_packed as deserialize;
+    #[allow(unused)]
+    pub use ser::packed::serialize_packed as serialize;
+
+    pub mod option {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::option_bytes as schema;
+        #[allow(unused)]
+        pub use de::packed::deserialize_option_packed as deserialize;
+        #[allow(unused)]
+        pub use ser::packed::serialize_option_packed as serialize;
+    }
+
+    /// Sorted `Vec<u64>` or `Vec<i64>` stored as delta-encoded LEB128 integers.
+    pub mod delta {
+        pub use super::*;
+        pub use crate::avro_bytes::packed::Delta;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::packed::deserialize_delta as deserialize;
+        #[allow(unused)]
+        pub use ser::packed::serialize_delta as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::packed::deserialize_option_delta as deserialize;
+            #[allow(unused)]
+            pub use ser::packed::serialize_option_delta as serialize;
+        }
+    }
+}
+
+pub mod utf8 {
+    pub use super::*;
+
+    /// `String` fields stored as bytes, refusing invalid UTF-8 with the offset of the first invalid byte.
+    pub mod strict {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::utf8::deserialize_strict_string as deserialize;
+        #[allow(unused)]
+        pub use ser::bytes::serialize_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_option_strict_string as deserialize;
+            #[allow(unused)]
+            pub use ser::bytes::serialize_option_bytes as serialize;
+        }
+
+        pub mod list {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::list as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_list_strict_string as deserialize;
+            #[allow(unused)]
+            pub use ser::list::serialize_list_bytes as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_list as schema;
+                #[allow(unused)]
+                pub use de::utf8::deserialize_option_list_strict_string as deserialize;
+                #[allow(unused)]
+                pub use ser::list::serialize_option_list_bytes as serialize;
+            }
+        }
+
+        pub mod hashmap {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_hashmap_strict_string as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_hashmap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::utf8::deserialize_option_hashmap_strict_string as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_hashmap as serialize;
+            }
+        }
+
+        pub mod btreemap {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_btreemap_strict_string as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_btreemap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::utf8::deserialize_option_btreemap_strict_string as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_btreemap as serialize;
+            }
+        }
+    }
+
+    /// `String` fields stored as bytes, replacing invalid UTF-8 with `U+FFFD`.
+    pub mod lossy {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::utf8::deserialize_lossy_string as deserialize;
+        #[allow(unused)]
+        pub use ser::bytes::serialize_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_option_lossy_string as deserialize;
+            #[allow(unused)]
+            pub use ser::bytes::serialize_option_bytes as serialize;
+        }
+
+        pub mod list {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::list as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_list_lossy_string as deserialize;
+            #[allow(unused)]
+            pub use ser::list::serialize_list_bytes as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_list as schema;
+                #[allow(unused)]
+                pub use de::utf8::deserialize_option_list_lossy_string as deserialize;
+                #[allow(unused)]
+                pub use ser::list::serialize_option_list_bytes as serialize;
+            }
+        }
+
+        pub mod hashmap {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_hashmap_lossy_string as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_hashmap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::utf8::deserialize_option_hashmap_lossy_string as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_hashmap as serialize;
+            }
+        }
+
+        pub mod btreemap {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::utf8::deserialize_btreemap_lossy_string as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_btreemap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::utf8::deserialize_option_btreemap_lossy_string as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_btreemap as serialize;
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+pub mod path {
+    pub use super::*;
+    #[allow(unused)]
+    pub use crate::schema::bytes as schema;
+    #[allow(unused)]
+    pub use de::path::deserialize_path as deserialize;
+    #[allow(unused)]
+    pub use ser::path::serialize_path as serialize;
+
+    pub mod option {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::option_bytes as schema;
+        #[allow(unused)]
+        pub use de::path::deserialize_option_path as deserialize;
+        #[allow(unused)]
+        pub use ser::path::serialize_option_path as serialize;
+    }
+
+    pub mod list {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::list as schema;
+        #[allow(unused)]
+        pub use de::path::deserialize_list_path as deserialize;
+        #[allow(unused)]
+        pub use ser::path::serialize_list_path as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_list as schema;
+            #[allow(unused)]
+            pub use de::path::deserialize_option_list_path as deserialize;
+            #[allow(unused)]
+            pub use ser::path::serialize_option_list_path as serialize;
+        }
+    }
+}
+
+#[cfg(feature = "secret")]
+pub mod secret {
+    pub use super::*;
+
+    /// `Zeroizing<Vec<u8>>` fields, wiped on drop including the buffers decoding legacy
+    /// arrays of integers from self-describing formats.
+    pub mod zeroizing {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::secret::deserialize_zeroizing_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::bytes::serialize_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::secret::deserialize_option_zeroizing_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::bytes::serialize_option_bytes as serialize;
+        }
+
+        pub mod list {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::list as schema;
+            #[allow(unused)]
+            pub use de::secret::deserialize_list_zeroizing_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::list::serialize_list_bytes as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_list as schema;
+                #[allow(unused)]
+                pub use de::secret::deserialize_option_list_zeroizing_bytes as deserialize;
+                #[allow(unused)]
+                pub use ser::list::serialize_option_list_bytes as serialize;
+            }
+        }
+
+        /// `HashMap<Vec<u8>, Zeroizing<Vec<u8>>>` fields, whose values are secret.
+        pub mod hashmap {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::secret::deserialize_hashmap_zeroizing_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_hashmap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::secret::deserialize_option_hashmap_zeroizing_bytes as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_hashmap as serialize;
+            }
+        }
+
+        /// `BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>` fields, whose values are secret.
+        pub mod btreemap {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::secret::deserialize_btreemap_zeroizing_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_btreemap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::secret::deserialize_option_btreemap_zeroizing_bytes as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_btreemap as serialize;
+            }
+        }
+    }
+
+    /// `secrecy::SecretVec<u8>` fields, exposed only while being serialized.
+    pub mod secrecy {
+        pub use super::*;
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::secret::deserialize_secret_bytes as deserialize;
+        #[allow(unused)]
+        pub use ser::secret::serialize_secret_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::secret::deserialize_option_secret_bytes as deserialize;
+            #[allow(unused)]
+            pub use ser::secret::serialize_option_secret_bytes as serialize;
+        }
+    }
+}
+
+pub mod extra {
+    pub use super::*;
+
+    #[cfg(feature = "bstr")]
+    pub mod bstr {
+        pub use super::*;
+
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        pub use de::bstr::deserialize;
+        pub use ser::bytes::serialize_bytes as serialize;
+
+        pub mod option {
+            pub use super::*;
+
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::bstr::deserialize_option as deserialize;
+            #[allow(unused)]
+            pub use ser::bytes::serialize_option_bytes as serialize;
+        }
+
+        pub mod list {
+            pub use super::*;
+
+            #[allow(unused)]
+            pub use crate::schema::list as schema;
+            #[allow(unused)]
+            pub use de::bstr::deserialize_list as deserialize;
+            #[allow(unused)]
+            pub use ser::list::serialize_list_bytes as serialize;
+
+            pub mod option {
+                pub use super::*;
+
+                #[allow(unused)]
+                pub use crate::schema::option_list as schema;
+                #[allow(unused)]
+                pub use de::bstr::deserialize_option_list as deserialize;
+                #[allow(unused)]
+                pub use ser::list::serialize_option_list_bytes as serialize;
+            }
+        }
+
+        pub mod hashmap {
+            pub use super::*;
+
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::bstr::deserialize_hashmap as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_hashmap as serialize;
+
+            pub mod option {
+                pub use super::*;
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::bstr::deserialize_option_hashmap as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_hashmap as serialize;
+            }
+        }
+
+        pub mod btreemap {
+            pub use super::*;
+
+            #[allow(unused)]
+            pub use crate::schema::map as schema;
+            #[allow(unused)]
+            pub use de::bstr::deserialize_btreemap as deserialize;
+            #[allow(unused)]
+            pub use ser::map::serialize_btreemap as serialize;
+
+            pub mod option {
+                pub use super::*;
+
+                #[allow(unused)]
+                pub use crate::schema::option_map as schema;
+                #[allow(unused)]
+                pub use de::bstr::deserialize_option_btreemap as deserialize;
+                #[allow(unused)]
+                pub use ser::map::serialize_option_btreemap as serialize;
+            }
+        }
+    }
+
+    #[cfg(feature = "bitvec")]
+    pub mod bitvec {
+        pub use super::*;
+
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::bits::deserialize_bitvec as deserialize;
+        #[allow(unused)]
+        pub use ser::bits::serialize_bitvec as serialize;
+
+        pub mod option {
+            pub use super::*;
+
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::bits::deserialize_option_bitvec as deserialize;
+            #[allow(unused)]
+            pub use ser::bits::serialize_option_bitvec as serialize;
+        }
+    }
+
+    #[cfg(feature = "fixedbitset")]
+    pub mod fixedbitset {
+        pub use super::*;
+
+        #[allow(unused)]
+        pub use crate::schema::bytes as schema;
+        #[allow(unused)]
+        pub use de::bits::deserialize_fixedbitset as deserialize;
+        #[allow(unused)]
+        pub use ser::bits::serialize_fixedbitset as serialize;
+
+        pub mod option {
+            pub use super::*;
+
+            #[allow(unused)]
+            pub use crate::schema::option_bytes as schema;
+            #[allow(unused)]
+            pub use de::bits::deserialize_option_fixedbitset as deserialize;
+            #[allow(unused)]
+            pub use ser::bits::serialize_option_fixedbitset as serialize;
         }
     }
 }
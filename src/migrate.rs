@@ -0,0 +1,477 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{Display, Formatter},
+    io::{Read, Write},
+};
+
+use apache_avro::{schema::UnionSchema, types::Value, Codec, Reader, Schema, Writer};
+
+use crate::audit::render;
+
+/// Failure while rewriting legacy `array<int>` byte fields.
+#[derive(Debug)]
+pub enum MigrationError {
+    Avro(apache_avro::Error),
+    Io(std::io::Error),
+    /// The field path does not lead to a record field of the writer schema.
+    UnknownField(String),
+    /// The field exists but is neither `array<int>` nor a union holding one.
+    NotAnIntArray(String),
+    /// An element of the legacy array does not fit in a `u8`.
+    OutOfRange {
+        field: String,
+        index: usize,
+        value: i32,
+    },
+    /// A datum does not match the shape announced by the writer schema.
+    UnexpectedValue(String),
+}
+
+impl Display for MigrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationError::Avro(err) => write!(f, "avro error: {err}"),
+            MigrationError::Io(err) => write!(f, "io error: {err}"),
+            MigrationError::UnknownField(field) => write!(f, "unknown field `{field}`"),
+            MigrationError::NotAnIntArray(field) => {
+                write!(f, "field `{field}` is not an array of int")
+            }
+            MigrationError::OutOfRange {
+                field,
+                index,
+                value,
+            } => write!(
+                f,
+                "field `{field}` holds {value} at index {index} which is not in 0..=255"
+            ),
+            MigrationError::UnexpectedValue(field) => {
+                write!(f, "field `{field}` holds a value not matching its schema")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MigrationError::Avro(err) => Some(err),
+            MigrationError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<apache_avro::Error> for MigrationError {
+    fn from(err: apache_avro::Error) -> Self {
+        MigrationError::Avro(err)
+    }
+}
+
+impl From<std::io::Error> for MigrationError {
+    fn from(err: std::io::Error) -> Self {
+        MigrationError::Io(err)
+    }
+}
+
+/// Returns a copy of `schema` where every field designated by a path has its
+/// `array<int>` type replaced by `bytes`.
+///
+/// Paths join record fields with `.` (e.g. `"payload"` or `"header.key"`) and go
+/// through arrays and maps of records, optionally marked with `[]` and `[value]`
+/// as `audit` reports them (e.g. `"items.key"` or `"items[].key"`). A field of a
+/// named record is rewritten in its definition, so wherever the record is used.
+pub fn migrate_schema(schema: &Schema, fields: &[&str]) -> Result<Schema, MigrationError> {
+    let names = names(schema);
+    let mut targets = BTreeSet::new();
+    for field in fields {
+        let path = parse(field)?;
+        let mut locator = Locator {
+            names: &names,
+            field,
+            targets: &mut targets,
+        };
+        locator.locate(schema, &path, Target::default())?;
+    }
+
+    let mut schema = schema.clone();
+    rewrite_definitions(&mut schema, &targets)?;
+    for target in targets.iter().filter(|target| target.field.is_none()) {
+        rewrite_at(&mut schema, &target.steps)?;
+    }
+    Ok(schema)
+}
+
+/// Rewrites a datum written with `writer_schema` into a datum of `migrated_schema`,
+/// as returned by [`migrate_schema`], converting its legacy `array<int>` values
+/// wherever `migrated_schema` declares `bytes`.
+pub fn migrate_value(
+    value: Value,
+    writer_schema: &Schema,
+    migrated_schema: &Schema,
+) -> Result<Value, MigrationError> {
+    Converter::new(writer_schema, migrated_schema).convert(value)
+}
+
+/// Copies an Avro object container file from `input` to `output`, converting the
+/// designated fields from `array<int>` to `bytes`.
+///
+/// Nothing is written to `output` unless every record is converted.
+///
+/// Returns the number of records written.
+pub fn migrate<R: Read, W: Write>(
+    input: R,
+    mut output: W,
+    fields: &[&str],
+    codec: Codec,
+) -> Result<usize, MigrationError> {
+    let reader = Reader::new(input)?;
+    let writer_schema = reader.writer_schema().clone();
+    let schema = migrate_schema(&writer_schema, fields)?;
+    let converter = Converter::new(&writer_schema, &schema);
+    let metadata = reader
+        .user_metadata()
+        .iter()
+        .filter(|(key, _)| !key.starts_with("avro."))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+
+    let mut writer = Writer::with_codec(&schema, Vec::new(), codec);
+    for (key, value) in metadata {
+        writer.add_user_metadata(key, value)?;
+    }
+
+    let mut count = 0;
+    for value in reader {
+        writer.append(converter.convert(value?)?)?;
+        count += 1;
+    }
+    output.write_all(&writer.into_inner()?)?;
+    output.flush()?;
+    Ok(count)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Segment<'a> {
+    Field(&'a str),
+    Item,
+    Value,
+}
+
+fn parse(field: &str) -> Result<Vec<Segment<'_>>, MigrationError> {
+    let mut segments = vec![];
+    for part in field.split('.') {
+        let (name, mut markers) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if !name.is_empty() {
+            segments.push(Segment::Field(name));
+        }
+        while !markers.is_empty() {
+            if let Some(rest) = markers.strip_prefix("[]") {
+                segments.push(Segment::Item);
+                markers = rest;
+            } else if let Some(rest) = markers.strip_prefix("[value]") {
+                segments.push(Segment::Value);
+                markers = rest;
+            } else {
+                return Err(MigrationError::UnknownField(field.to_string()));
+            }
+        }
+    }
+    Ok(segments)
+}
+
+/// Named types of a schema by full name.
+fn names(schema: &Schema) -> HashMap<String, &Schema> {
+    fn collect<'a>(schema: &'a Schema, names: &mut HashMap<String, &'a Schema>) {
+        match schema {
+            Schema::Record(record) => {
+                names.insert(record.name.fullname(None), schema);
+                for field in &record.fields {
+                    collect(&field.schema, names);
+                }
+            }
+            Schema::Array(inner) | Schema::Map(inner) => collect(inner, names),
+            Schema::Union(union) => {
+                for variant in union.variants() {
+                    collect(variant, names);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut names = HashMap::new();
+    collect(schema, &mut names);
+    names
+}
+
+fn resolve<'a>(schema: &'a Schema, names: &HashMap<String, &'a Schema>) -> Option<&'a Schema> {
+    match schema {
+        Schema::Ref { name } => names.get(&name.fullname(None)).copied(),
+        schema => Some(schema),
+    }
+}
+
+fn is_int_array(schema: &Schema) -> bool {
+    matches!(schema, Schema::Array(items) if **items == Schema::Int)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Step {
+    Item,
+    Value,
+    Branch(usize),
+}
+
+/// Location of an `array<int>` to rewrite: the anonymous steps from a field of a
+/// named record, given by the record and field names, or from the root schema.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Target {
+    field: Option<(String, String)>,
+    steps: Vec<Step>,
+}
+
+impl Target {
+    fn then(&self, step: Step) -> Target {
+        let mut target = self.clone();
+        target.steps.push(step);
+        target
+    }
+}
+
+struct Locator<'a, 's> {
+    names: &'a HashMap<String, &'s Schema>,
+    field: &'a str,
+    targets: &'a mut BTreeSet<Target>,
+}
+
+impl<'s> Locator<'_, 's> {
+    fn locate(
+        &mut self,
+        schema: &'s Schema,
+        path: &[Segment],
+        at: Target,
+    ) -> Result<(), MigrationError> {
+        let Some(schema) = resolve(schema, self.names) else {
+            return Err(MigrationError::UnknownField(self.field.to_string()));
+        };
+        match (path, schema) {
+            ([], schema) if is_int_array(schema) => {
+                self.targets.insert(at);
+                Ok(())
+            }
+            ([], Schema::Union(union)) => {
+                let branches = union
+                    .variants()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, variant)| is_int_array(variant))
+                    .map(|(index, _)| at.then(Step::Branch(index)))
+                    .collect::<Vec<_>>();
+                if branches.is_empty() {
+                    return Err(MigrationError::NotAnIntArray(self.field.to_string()));
+                }
+                self.targets.extend(branches);
+                Ok(())
+            }
+            ([], _) => Err(MigrationError::NotAnIntArray(self.field.to_string())),
+            ([Segment::Field(name), rest @ ..], Schema::Record(record)) => {
+                let Some(&position) = record.lookup.get(*name) else {
+                    return Err(MigrationError::UnknownField(self.field.to_string()));
+                };
+                let at = Target {
+                    field: Some((record.name.fullname(None), name.to_string())),
+                    steps: vec![],
+                };
+                self.locate(&record.fields[position].schema, rest, at)
+            }
+            ([Segment::Item, rest @ ..], Schema::Array(items)) => {
+                self.locate(items, rest, at.then(Step::Item))
+            }
+            ([Segment::Field(_), ..], Schema::Array(items)) => {
+                self.locate(items, path, at.then(Step::Item))
+            }
+            ([Segment::Value, rest @ ..], Schema::Map(values)) => {
+                self.locate(values, rest, at.then(Step::Value))
+            }
+            ([Segment::Field(_), ..], Schema::Map(values)) => {
+                self.locate(values, path, at.then(Step::Value))
+            }
+            (path, Schema::Union(union)) => {
+                // every branch holding the field is rewritten, the others are left as is
+                let mut found = false;
+                for (index, variant) in union.variants().iter().enumerate() {
+                    match self.locate(variant, path, at.then(Step::Branch(index))) {
+                        Ok(()) => found = true,
+                        Err(MigrationError::UnknownField(_)) => {}
+                        Err(err) => return Err(err),
+                    }
+                }
+                if !found {
+                    return Err(MigrationError::UnknownField(self.field.to_string()));
+                }
+                Ok(())
+            }
+            _ => Err(MigrationError::UnknownField(self.field.to_string())),
+        }
+    }
+}
+
+/// Rewrites the targets located in the fields of the named records defined in `schema`.
+fn rewrite_definitions(
+    schema: &mut Schema,
+    targets: &BTreeSet<Target>,
+) -> Result<(), MigrationError> {
+    match schema {
+        Schema::Record(record) => {
+            for field in record.fields.iter_mut() {
+                rewrite_definitions(&mut field.schema, targets)?;
+            }
+            let name = record.name.fullname(None);
+            for target in targets {
+                let Some((record_name, field)) = &target.field else {
+                    continue;
+                };
+                if *record_name == name {
+                    let position = record.lookup[field];
+                    rewrite_at(&mut record.fields[position].schema, &target.steps)?;
+                }
+            }
+            Ok(())
+        }
+        Schema::Array(inner) | Schema::Map(inner) => rewrite_definitions(inner, targets),
+        Schema::Union(union) => {
+            let mut variants = union.variants().to_vec();
+            for variant in variants.iter_mut() {
+                rewrite_definitions(variant, targets)?;
+            }
+            *union = UnionSchema::new(variants)?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn rewrite_at(schema: &mut Schema, steps: &[Step]) -> Result<(), MigrationError> {
+    match (steps, schema) {
+        ([], schema) => {
+            *schema = Schema::Bytes;
+            Ok(())
+        }
+        ([Step::Item, rest @ ..], Schema::Array(items)) => rewrite_at(items, rest),
+        ([Step::Value, rest @ ..], Schema::Map(values)) => rewrite_at(values, rest),
+        ([Step::Branch(index), rest @ ..], Schema::Union(union)) => {
+            let mut variants = union.variants().to_vec();
+            rewrite_at(&mut variants[*index], rest)?;
+            *union = UnionSchema::new(variants)?;
+            Ok(())
+        }
+        _ => unreachable!("targets are located in the schema they rewrite"),
+    }
+}
+
+/// Converts the datums of a writer schema into datums of its migrated schema.
+struct Converter<'s> {
+    writer_names: HashMap<String, &'s Schema>,
+    migrated_names: HashMap<String, &'s Schema>,
+    writer_schema: &'s Schema,
+    migrated_schema: &'s Schema,
+}
+
+impl<'s> Converter<'s> {
+    fn new(writer_schema: &'s Schema, migrated_schema: &'s Schema) -> Self {
+        Converter {
+            writer_names: names(writer_schema),
+            migrated_names: names(migrated_schema),
+            writer_schema,
+            migrated_schema,
+        }
+    }
+
+    fn convert(&self, mut value: Value) -> Result<Value, MigrationError> {
+        self.rewrite(
+            &mut value,
+            self.writer_schema,
+            self.migrated_schema,
+            &mut vec![],
+        )?;
+        Ok(value)
+    }
+
+    fn rewrite(
+        &self,
+        value: &mut Value,
+        writer: &'s Schema,
+        migrated: &'s Schema,
+        path: &mut Vec<String>,
+    ) -> Result<(), MigrationError> {
+        let (Some(writer), Some(migrated)) = (
+            resolve(writer, &self.writer_names),
+            resolve(migrated, &self.migrated_names),
+        ) else {
+            return Ok(());
+        };
+        match (value, writer, migrated) {
+            (value @ Value::Array(_), writer, Schema::Bytes) if is_int_array(writer) => {
+                let Value::Array(items) = std::mem::replace(value, Value::Null) else {
+                    unreachable!()
+                };
+                let bytes = items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| match item {
+                        Value::Int(v) => u8::try_from(v).map_err(|_| MigrationError::OutOfRange {
+                            field: render(path),
+                            index,
+                            value: v,
+                        }),
+                        _ => Err(MigrationError::UnexpectedValue(render(path))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                *value = Value::Bytes(bytes);
+                Ok(())
+            }
+            (Value::Array(items), Schema::Array(writer), Schema::Array(migrated)) => {
+                path.push("[]".to_string());
+                for item in items.iter_mut() {
+                    self.rewrite(item, writer, migrated, path)?;
+                }
+                path.pop();
+                Ok(())
+            }
+            (Value::Map(values), Schema::Map(writer), Schema::Map(migrated)) => {
+                path.push("[value]".to_string());
+                for value in values.values_mut() {
+                    self.rewrite(value, writer, migrated, path)?;
+                }
+                path.pop();
+                Ok(())
+            }
+            (Value::Union(index, inner), Schema::Union(writer), Schema::Union(migrated)) => {
+                let index = *index as usize;
+                match (writer.variants().get(index), migrated.variants().get(index)) {
+                    (Some(writer), Some(migrated)) => self.rewrite(inner, writer, migrated, path),
+                    _ => Err(MigrationError::UnexpectedValue(render(path))),
+                }
+            }
+            (Value::Record(fields), Schema::Record(writer), Schema::Record(migrated)) => {
+                for (name, value) in fields.iter_mut() {
+                    let (Some(&writer_position), Some(&migrated_position)) =
+                        (writer.lookup.get(name), migrated.lookup.get(name))
+                    else {
+                        return Err(MigrationError::UnexpectedValue(render(path)));
+                    };
+                    path.push(name.clone());
+                    self.rewrite(
+                        value,
+                        &writer.fields[writer_position].schema,
+                        &migrated.fields[migrated_position].schema,
+                        path,
+                    )?;
+                    path.pop();
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, HashSet};
+
+use apache_avro::schema::{Name, RecordField, RecordFieldOrder, RecordSchema, Schema, UnionSchema};
+
+use crate::avro_bytes::ser::pair::{KEY, PAIR, VALUE};
+
+/// Schema of a field annotated with `bytes`.
+pub fn bytes() -> Schema {
+    Schema::Bytes
+}
+
+/// Schema of a field annotated with `bytes::option`.
+pub fn option_bytes() -> Schema {
+    nullable(bytes())
+}
+
+/// Schema of a field annotated with `list`.
+pub fn list() -> Schema {
+    Schema::Array(Box::new(bytes()))
+}
+
+/// Schema of a field annotated with `list::option`.
+pub fn option_list() -> Schema {
+    nullable(list())
+}
+
+/// The `Pair` record holding a map entry, also the schema of a field annotated with `tuple`.
+pub fn pair() -> Schema {
+    record(PAIR, [(KEY, bytes()), (VALUE, bytes())]).expect("valid Pair record")
+}
+
+/// Schema of a field annotated with `tuple::option`.
+pub fn option_pair() -> Schema {
+    nullable(pair())
+}
+
+/// Schema of a field annotated with `hashmap`, `btreemap` or `tuple::list`.
+pub fn map() -> Schema {
+    Schema::Array(Box::new(pair()))
+}
+
+/// Schema of a field annotated with `hashmap::option`, `btreemap::option` or `tuple::list::option`.
+pub fn option_map() -> Schema {
+    nullable(map())
+}
+
+/// Union of `null` and the given schema, as expected by `Option` fields.
+pub fn nullable(schema: Schema) -> Schema {
+    Schema::Union(UnionSchema::new(vec![Schema::Null, schema]).expect("valid nullable union"))
+}
+
+/// Builds a record schema from its fields.
+///
+/// Named schemas defined more than once, such as the `Pair` record of several
+/// map fields, are kept at their first definition and referenced afterwards.
+pub fn record<'a>(
+    name: &str,
+    fields: impl IntoIterator<Item = (&'a str, Schema)>,
+) -> Result<Schema, apache_avro::Error> {
+    let name = Name::new(name)?;
+    let mut names = HashSet::from([name.clone()]);
+    let mut lookup = BTreeMap::new();
+    let mut record_fields = vec![];
+
+    for (position, (field, mut schema)) in fields.into_iter().enumerate() {
+        dedup_named(&mut schema, &mut names)?;
+        lookup.insert(field.to_string(), position);
+        record_fields.push(RecordField {
+            name: field.to_string(),
+            doc: None,
+            aliases: None,
+            default: None,
+            schema,
+            order: RecordFieldOrder::Ascending,
+            position,
+            custom_attributes: BTreeMap::new(),
+        });
+    }
+
+    Ok(Schema::Record(RecordSchema {
+        name,
+        aliases: None,
+        doc: None,
+        fields: record_fields,
+        lookup,
+        attributes: BTreeMap::new(),
+    }))
+}
+
+fn dedup_named(schema: &mut Schema, names: &mut HashSet<Name>) -> Result<(), apache_avro::Error> {
+    let name = match schema {
+        Schema::Record(RecordSchema { name, .. }) => name.clone(),
+        Schema::Enum(enum_schema) => enum_schema.name.clone(),
+        Schema::Fixed(fixed) => fixed.name.clone(),
+        Schema::Array(items) | Schema::Map(items) => return dedup_named(items, names),
+        Schema::Union(union) => {
+            let mut variants = union.variants().to_vec();
+            for variant in variants.iter_mut() {
+                dedup_named(variant, names)?;
+            }
+            *union = UnionSchema::new(variants)?;
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+
+    if !names.insert(name.clone()) {
+        *schema = Schema::Ref { name };
+    } else if let Schema::Record(record) = schema {
+        for field in record.fields.iter_mut() {
+            dedup_named(&mut field.schema, names)?;
+        }
+    }
+    Ok(())
+}
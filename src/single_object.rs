@@ -0,0 +1,136 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use apache_avro::{rabin::Rabin, AvroSchema, Schema};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Two bytes opening every single-object encoded datum.
+pub const MARKER: [u8; 2] = [0xC3, 0x01];
+
+/// Size of the marker followed by the little-endian schema fingerprint.
+pub const HEADER_SIZE: usize = 10;
+
+#[derive(Debug)]
+pub enum SingleObjectError {
+    Avro(apache_avro::Error),
+    /// The message is shorter than the single-object header.
+    Truncated(usize),
+    InvalidMarker([u8; 2]),
+    /// No schema of the table has this fingerprint.
+    UnknownFingerprint(u64),
+}
+
+impl Display for SingleObjectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SingleObjectError::Avro(err) => write!(f, "avro error: {err}"),
+            SingleObjectError::Truncated(length) => write!(
+                f,
+                "message of {length} bytes is shorter than the single-object header"
+            ),
+            SingleObjectError::InvalidMarker(marker) => write!(
+                f,
+                "invalid single-object marker {:#04x} {:#04x}",
+                marker[0], marker[1]
+            ),
+            SingleObjectError::UnknownFingerprint(fingerprint) => {
+                write!(f, "unknown schema fingerprint {fingerprint:#018x}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SingleObjectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SingleObjectError::Avro(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<apache_avro::Error> for SingleObjectError {
+    fn from(err: apache_avro::Error) -> Self {
+        SingleObjectError::Avro(err)
+    }
+}
+
+/// CRC-64-AVRO (Rabin) fingerprint of the canonical form of `schema`.
+pub fn fingerprint(schema: &Schema) -> u64 {
+    let fingerprint = schema.fingerprint::<Rabin>();
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&fingerprint.bytes);
+    u64::from_le_bytes(bytes)
+}
+
+/// Schemas known locally, indexed by fingerprint.
+#[derive(Debug, Default, Clone)]
+pub struct SchemaTable {
+    schemas: HashMap<u64, Schema>,
+}
+
+impl SchemaTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `schema` to the table and returns its fingerprint.
+    pub fn insert(&mut self, schema: Schema) -> u64 {
+        let fingerprint = fingerprint(&schema);
+        self.schemas.insert(fingerprint, schema);
+        fingerprint
+    }
+
+    /// Adds the schema of `T` to the table and returns its fingerprint.
+    pub fn insert_schema_of<T: AvroSchema>(&mut self) -> u64 {
+        self.insert(T::get_schema())
+    }
+
+    pub fn get(&self, fingerprint: u64) -> Option<&Schema> {
+        self.schemas.get(&fingerprint)
+    }
+}
+
+/// Encodes `record` with `schema` behind the single-object header.
+pub fn encode_single_object<T: Serialize>(
+    schema: &Schema,
+    record: &T,
+) -> Result<Vec<u8>, SingleObjectError> {
+    let value = apache_avro::to_value(record)?;
+    let datum = apache_avro::to_avro_datum(schema, value)?;
+
+    let mut message = Vec::with_capacity(HEADER_SIZE + datum.len());
+    message.extend_from_slice(&MARKER);
+    message.extend_from_slice(&fingerprint(schema).to_le_bytes());
+    message.extend_from_slice(&datum);
+    Ok(message)
+}
+
+/// Splits a single-object encoded message into its schema fingerprint and Avro body.
+pub fn split(message: &[u8]) -> Result<(u64, &[u8]), SingleObjectError> {
+    if message.len() < HEADER_SIZE {
+        return Err(SingleObjectError::Truncated(message.len()));
+    }
+    let (header, body) = message.split_at(HEADER_SIZE);
+    if header[..2] != MARKER {
+        return Err(SingleObjectError::InvalidMarker([header[0], header[1]]));
+    }
+    let mut fingerprint = [0; 8];
+    fingerprint.copy_from_slice(&header[2..]);
+    Ok((u64::from_le_bytes(fingerprint), body))
+}
+
+/// Decodes a single-object encoded message with the schema of `table` matching its fingerprint.
+pub fn decode_single_object<T: DeserializeOwned>(
+    table: &SchemaTable,
+    message: &[u8],
+) -> Result<T, SingleObjectError> {
+    let (fingerprint, mut body) = split(message)?;
+    let schema = table
+        .get(fingerprint)
+        .ok_or(SingleObjectError::UnknownFingerprint(fingerprint))?;
+    let value = apache_avro::from_avro_datum(schema, &mut body, None)?;
+    Ok(apache_avro::from_value(&value)?)
+}
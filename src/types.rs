@@ -0,0 +1,279 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
+    ops::{Deref, DerefMut},
+};
+
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::avro_bytes::{de, ser};
+
+/// Number of leading bytes shown when no precision is given.
+const PREVIEW: usize = 32;
+
+/// Bytes encoded as Avro `bytes`, like a field annotated with the `bytes` module.
+///
+/// `Debug` and `Display` show them as an escaped ASCII string, or in hexadecimal
+/// with the alternate flag (`{:#}`), truncated to 32 bytes unless a precision is
+/// given (`{:.64}`).
+///
+/// It dereferences to `[u8]` and hashes and compares like it, so it can be used as a
+/// type parameter of any container, or as the key of a Rust map looked up with a
+/// `&[u8]`. Avro map keys are strings: such a map cannot be encoded with apache-avro,
+/// which `AvroBytesMap` does.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AvroBytes(pub Vec<u8>);
+
+/// Borrowed counterpart of `AvroBytes`.
+///
+/// It can only be deserialized from formats lending their input, such as
+/// `bincode`; `apache_avro::from_value` copies bytes out of the `Value` and
+/// requires `AvroBytes`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AvroByteSlice<'a>(pub &'a [u8]);
+
+/// Map of bytes encoded as an array of `Pair` records, like a field annotated with
+/// the `hashmap` module.
+///
+/// Entries are shown sorted by key, each key and value being formatted like `AvroBytes`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct AvroBytesMap(pub HashMap<Vec<u8>, Vec<u8>>);
+
+fn write_bytes(f: &mut Formatter<'_>, bytes: &[u8]) -> std::fmt::Result {
+    let shown = &bytes[..bytes.len().min(f.precision().unwrap_or(PREVIEW))];
+    if f.alternate() {
+        f.write_str("0x")?;
+        for byte in shown {
+            write!(f, "{byte:02x}")?;
+        }
+    } else {
+        f.write_str("b\"")?;
+        for &byte in shown {
+            match byte {
+                b'"' => f.write_str("\\\"")?,
+                b'\\' => f.write_str("\\\\")?,
+                b'\n' => f.write_str("\\n")?,
+                b'\r' => f.write_str("\\r")?,
+                b'\t' => f.write_str("\\t")?,
+                0x20..=0x7e => write!(f, "{}", byte as char)?,
+                _ => write!(f, "\\x{byte:02x}")?,
+            }
+        }
+        f.write_str("\"")?;
+    }
+    if shown.len() < bytes.len() {
+        write!(f, "... ({} bytes)", bytes.len())?;
+    }
+    Ok(())
+}
+
+fn write_map(f: &mut Formatter<'_>, map: &HashMap<Vec<u8>, Vec<u8>>) -> std::fmt::Result {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort();
+    f.write_str("{")?;
+    for (index, (key, value)) in entries.into_iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write_bytes(f, key)?;
+        f.write_str(": ")?;
+        write_bytes(f, value)?;
+    }
+    f.write_str("}")
+}
+
+impl Display for AvroBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_bytes(f, &self.0)
+    }
+}
+
+impl Debug for AvroBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_bytes(f, &self.0)
+    }
+}
+
+impl Display for AvroByteSlice<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_bytes(f, self.0)
+    }
+}
+
+impl Debug for AvroByteSlice<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_bytes(f, self.0)
+    }
+}
+
+impl Display for AvroBytesMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_map(f, &self.0)
+    }
+}
+
+impl Debug for AvroBytesMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_map(f, &self.0)
+    }
+}
+
+impl Serialize for AvroBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ser::bytes::serialize_bytes(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AvroBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        de::bytes::deserialize_bytes(deserializer).map(AvroBytes)
+    }
+}
+
+impl Serialize for AvroByteSlice<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for AvroByteSlice<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(ByteSliceVisitor)
+    }
+}
+
+struct ByteSliceVisitor;
+
+impl<'de> Visitor<'de> for ByteSliceVisitor {
+    type Value = AvroByteSlice<'de>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(formatter, "borrowed bytes")
+    }
+
+    fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(AvroByteSlice(v))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(AvroByteSlice(v.as_bytes()))
+    }
+}
+
+impl Serialize for AvroBytesMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ser::map::serialize_hashmap(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AvroBytesMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        de::map::deserialize_hashmap(deserializer).map(AvroBytesMap)
+    }
+}
+
+impl AvroBytes {
+    /// Borrows the bytes as an `AvroByteSlice`.
+    pub fn as_byte_slice(&self) -> AvroByteSlice<'_> {
+        AvroByteSlice(&self.0)
+    }
+}
+
+impl AvroByteSlice<'_> {
+    /// Copies the bytes into an `AvroBytes`.
+    pub fn to_avro_bytes(&self) -> AvroBytes {
+        AvroBytes(self.0.to_vec())
+    }
+}
+
+impl Deref for AvroBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl DerefMut for AvroBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Deref for AvroByteSlice<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for AvroBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for AvroByteSlice<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl Borrow<[u8]> for AvroBytes {
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<[u8]> for AvroByteSlice<'_> {
+    fn borrow(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for AvroBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        AvroBytes(bytes)
+    }
+}
+
+impl From<&[u8]> for AvroBytes {
+    fn from(bytes: &[u8]) -> Self {
+        AvroBytes(bytes.to_vec())
+    }
+}
+
+impl From<AvroByteSlice<'_>> for AvroBytes {
+    fn from(bytes: AvroByteSlice<'_>) -> Self {
+        bytes.to_avro_bytes()
+    }
+}
+
+impl From<AvroBytes> for Vec<u8> {
+    fn from(bytes: AvroBytes) -> Self {
+        bytes.0
+    }
+}
+
+impl<'a> From<&'a [u8]> for AvroByteSlice<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        AvroByteSlice(bytes)
+    }
+}
+
+impl<'a> From<&'a AvroBytes> for AvroByteSlice<'a> {
+    fn from(bytes: &'a AvroBytes) -> Self {
+        bytes.as_byte_slice()
+    }
+}
+
+impl<'a> From<AvroByteSlice<'a>> for &'a [u8] {
+    fn from(bytes: AvroByteSlice<'a>) -> Self {
+        bytes.0
+    }
+}
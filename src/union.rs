@@ -0,0 +1,217 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use apache_avro::{
+    schema::{SchemaKind, UnionSchema},
+    types::Value,
+    Schema,
+};
+
+use crate::schema::record;
+
+/// Avro type of an enum variant.
+#[derive(Debug, Clone)]
+pub enum Branch {
+    /// A unit variant, encoded as `null`. An enum can have a single one.
+    Unit,
+    /// A newtype variant, encoded as the schema of its field, e.g.
+    /// `serde_avro_bytes::bytes::schema()` for a field annotated with `bytes`.
+    ///
+    /// A union holds a single branch of each unnamed type: two variants holding
+    /// `bytes` cannot both be `Newtype`.
+    Newtype(Schema),
+    /// A newtype variant, encoded as a record named after the variant whose
+    /// single field is named `_0`, which keeps it apart from other variants
+    /// holding the same type.
+    Wrapped(Schema),
+    /// A tuple variant, encoded as a record named after the variant whose
+    /// fields are named `_0`, `_1`...
+    Tuple(Vec<Schema>),
+}
+
+#[derive(Debug)]
+pub enum UnionError {
+    Avro(apache_avro::Error),
+    /// The value names a variant which is not part of the layout.
+    UnknownVariant(String),
+    /// The union index does not match any variant.
+    UnknownIndex(u32),
+    /// The value is not shaped as the variant it claims to be.
+    UnexpectedValue(String),
+    /// Two variants are encoded as the same unnamed Avro type, which a union cannot hold.
+    DuplicateBranch {
+        variant: String,
+        previous: String,
+    },
+}
+
+impl Display for UnionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnionError::Avro(err) => write!(f, "avro error: {err}"),
+            UnionError::UnknownVariant(variant) => write!(f, "unknown variant `{variant}`"),
+            UnionError::UnknownIndex(index) => write!(f, "no variant at union index {index}"),
+            UnionError::UnexpectedValue(variant) => {
+                write!(f, "value does not match variant `{variant}`")
+            }
+            UnionError::DuplicateBranch { variant, previous } => write!(
+                f,
+                "variants `{previous}` and `{variant}` have the same Avro type, \
+                 encode one of them with `Branch::Wrapped`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UnionError::Avro(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<apache_avro::Error> for UnionError {
+    fn from(err: apache_avro::Error) -> Self {
+        UnionError::Avro(err)
+    }
+}
+
+/// Encodes a Rust enum as an Avro union with one branch per variant, in
+/// declaration order, so that the union index is the variant index.
+///
+/// `apache_avro::to_value` represents a variant as a `type`/`value` record
+/// rather than a union; `to_union` and `from_union` convert between both.
+///
+/// This representation is not documented by apache-avro. As of 0.16, a unit
+/// variant is a `Value::String` of its name, and other variants are a record
+/// whose `type` is a `Value::Enum` of the variant and whose `value` is the
+/// field wrapped in a `Value::Union` for a newtype variant, or an array of the
+/// fields each wrapped in a `Value::Union` for a tuple variant. It has to be
+/// checked again when upgrading apache-avro.
+#[derive(Debug, Clone)]
+pub struct UnionLayout {
+    variants: Vec<(String, Branch)>,
+}
+
+impl UnionLayout {
+    pub fn new<'a>(variants: impl IntoIterator<Item = (&'a str, Branch)>) -> Self {
+        UnionLayout {
+            variants: variants
+                .into_iter()
+                .map(|(name, branch)| (name.to_string(), branch))
+                .collect(),
+        }
+    }
+
+    pub fn schema(&self) -> Result<Schema, UnionError> {
+        let branches = self
+            .variants
+            .iter()
+            .map(|(name, branch)| match branch {
+                Branch::Unit => Ok(Schema::Null),
+                Branch::Newtype(schema) => Ok(schema.clone()),
+                Branch::Wrapped(schema) => record(name, [("_0", schema.clone())]),
+                Branch::Tuple(fields) => {
+                    let names = tuple_fields(fields.len());
+                    record(
+                        name,
+                        names.iter().map(String::as_str).zip(fields.iter().cloned()),
+                    )
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut unnamed = HashMap::new();
+        for ((variant, _), branch) in self.variants.iter().zip(&branches) {
+            let kind = SchemaKind::from(branch);
+            if kind.is_named() {
+                continue;
+            }
+            if let Some(previous) = unnamed.insert(kind, variant) {
+                return Err(UnionError::DuplicateBranch {
+                    variant: variant.clone(),
+                    previous: previous.clone(),
+                });
+            }
+        }
+        Ok(Schema::Union(UnionSchema::new(branches)?))
+    }
+
+    /// Converts a value produced by `apache_avro::to_value` for the enum into the union value.
+    pub fn to_union(&self, value: Value) -> Result<Value, UnionError> {
+        let (index, payload) = match value {
+            Value::String(variant) => (self.index_of(&variant)?, None),
+            Value::Record(mut fields) if fields.len() == 2 => {
+                let (_, payload) = fields.pop().expect("value field");
+                let (_, tag) = fields.pop().expect("type field");
+                let Value::Enum(_, variant) = tag else {
+                    return Err(UnionError::UnexpectedValue(format!("{tag:?}")));
+                };
+                (self.index_of(&variant)?, Some(payload))
+            }
+            other => return Err(UnionError::UnexpectedValue(format!("{other:?}"))),
+        };
+
+        let (name, branch) = &self.variants[index];
+        let branch = match (branch, payload) {
+            (Branch::Unit, None) => Value::Null,
+            (Branch::Newtype(_), Some(Value::Union(_, inner))) => *inner,
+            (Branch::Wrapped(_), Some(Value::Union(_, inner))) => {
+                Value::Record(vec![("_0".to_string(), *inner)])
+            }
+            (Branch::Tuple(fields), Some(Value::Array(items))) if fields.len() == items.len() => {
+                // tuple variant items come wrapped in a union of the variant index
+                let items = items.into_iter().map(|item| match item {
+                    Value::Union(_, inner) => *inner,
+                    item => item,
+                });
+                Value::Record(tuple_fields(fields.len()).into_iter().zip(items).collect())
+            }
+            _ => return Err(UnionError::UnexpectedValue(name.clone())),
+        };
+        Ok(Value::Union(index as u32, Box::new(branch)))
+    }
+
+    /// Converts a union value into the representation expected by `apache_avro::from_value` for the enum.
+    pub fn from_union(&self, value: Value) -> Result<Value, UnionError> {
+        let Value::Union(index, inner) = value else {
+            return Err(UnionError::UnexpectedValue(format!("{value:?}")));
+        };
+        let (name, branch) = self
+            .variants
+            .get(index as usize)
+            .ok_or(UnionError::UnknownIndex(index))?;
+
+        let tag = Value::Enum(index, name.clone());
+        let payload = match (branch, *inner) {
+            (Branch::Unit, Value::Null) => return Ok(tag),
+            (Branch::Newtype(_), inner) => inner,
+            (Branch::Wrapped(_), Value::Record(mut fields)) if fields.len() == 1 => {
+                fields.pop().expect("wrapped field").1
+            }
+            (Branch::Tuple(_), Value::Record(fields)) => {
+                Value::Array(fields.into_iter().map(|(_, field)| field).collect())
+            }
+            _ => return Err(UnionError::UnexpectedValue(name.clone())),
+        };
+        Ok(Value::Record(vec![
+            ("type".to_string(), tag),
+            ("value".to_string(), payload),
+        ]))
+    }
+
+    fn index_of(&self, variant: &str) -> Result<usize, UnionError> {
+        self.variants
+            .iter()
+            .position(|(name, _)| name == variant)
+            .ok_or_else(|| UnionError::UnknownVariant(variant.to_string()))
+    }
+}
+
+fn tuple_fields(len: usize) -> Vec<String> {
+    (0..len).map(|index| format!("_{index}")).collect()
+}
@@ -0,0 +1,145 @@
+use std::fmt::{Display, Formatter};
+
+use apache_avro::types::Value;
+
+use crate::avro_bytes::ser::pair::{KEY, VALUE};
+
+/// Borrows the entries of an array of `Pair` records, as produced by the
+/// `hashmap` and `btreemap` modules.
+///
+/// Returns `None` when the value does not have this shape. A union holding
+/// such an array is looked through.
+pub fn pairs_to_map(value: &Value) -> Option<Vec<(&[u8], &[u8])>> {
+    match value {
+        Value::Union(_, inner) => pairs_to_map(inner),
+        Value::Array(items) => items.iter().map(as_pair).collect(),
+        _ => None,
+    }
+}
+
+/// Builds the array of `Pair` records holding the given entries.
+pub fn map_to_pairs<K: AsRef<[u8]>, V: AsRef<[u8]>>(
+    entries: impl IntoIterator<Item = (K, V)>,
+) -> Value {
+    Value::Array(
+        entries
+            .into_iter()
+            .map(|(key, value)| {
+                Value::Record(vec![
+                    (KEY.to_string(), Value::Bytes(key.as_ref().to_vec())),
+                    (VALUE.to_string(), Value::Bytes(value.as_ref().to_vec())),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn as_pair(value: &Value) -> Option<(&[u8], &[u8])> {
+    let Value::Record(fields) = value else {
+        return None;
+    };
+    let mut key = None;
+    let mut value = None;
+    for (name, field) in fields {
+        let Value::Bytes(bytes) = field else {
+            return None;
+        };
+        match name.as_str() {
+            KEY => key = Some(bytes.as_slice()),
+            VALUE => value = Some(bytes.as_slice()),
+            _ => return None,
+        }
+    }
+    Some((key?, value?))
+}
+
+/// Indented rendering of a `Value` tree.
+///
+/// Bytes are shown as a string when they hold printable UTF-8 and in
+/// hexadecimal otherwise, arrays of `Pair` records are shown as maps.
+pub struct Pretty<'a>(pub &'a Value);
+
+impl Display for Pretty<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_value(f, self.0, 0)
+    }
+}
+
+/// Shorthand for `Pretty(value)`.
+pub fn pretty(value: &Value) -> Pretty<'_> {
+    Pretty(value)
+}
+
+struct PrettyBytes<'a>(&'a [u8]);
+
+impl Display for PrettyBytes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match std::str::from_utf8(self.0) {
+            Ok(text) if !text.chars().any(char::is_control) => write!(f, "b{text:?}"),
+            _ => {
+                f.write_str("0x")?;
+                for byte in self.0 {
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn write_value(f: &mut Formatter<'_>, value: &Value, depth: usize) -> std::fmt::Result {
+    let indent = "  ".repeat(depth + 1);
+    let closing = "  ".repeat(depth);
+    match value {
+        Value::Null => f.write_str("null"),
+        Value::Boolean(v) => write!(f, "{v}"),
+        Value::Int(v) => write!(f, "{v}"),
+        Value::Long(v) => write!(f, "{v}"),
+        Value::Float(v) => write!(f, "{v}"),
+        Value::Double(v) => write!(f, "{v}"),
+        Value::String(v) => write!(f, "{v:?}"),
+        Value::Bytes(bytes) | Value::Fixed(_, bytes) => write!(f, "{}", PrettyBytes(bytes)),
+        Value::Enum(_, symbol) => f.write_str(symbol),
+        Value::Union(_, inner) => write_value(f, inner, depth),
+        Value::Array(items) if items.is_empty() => f.write_str("[]"),
+        Value::Array(items) => match pairs_to_map(value) {
+            Some(entries) => {
+                f.write_str("{\n")?;
+                for (key, value) in entries {
+                    writeln!(f, "{indent}{} => {}", PrettyBytes(key), PrettyBytes(value))?;
+                }
+                write!(f, "{closing}}}")
+            }
+            None => {
+                f.write_str("[\n")?;
+                for item in items {
+                    f.write_str(&indent)?;
+                    write_value(f, item, depth + 1)?;
+                    f.write_str("\n")?;
+                }
+                write!(f, "{closing}]")
+            }
+        },
+        Value::Map(entries) => {
+            let mut entries = entries.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+            f.write_str("{\n")?;
+            for (key, value) in entries {
+                write!(f, "{indent}{key:?} => ")?;
+                write_value(f, value, depth + 1)?;
+                f.write_str("\n")?;
+            }
+            write!(f, "{closing}}}")
+        }
+        Value::Record(fields) => {
+            f.write_str("{\n")?;
+            for (name, value) in fields {
+                write!(f, "{indent}{name}: ")?;
+                write_value(f, value, depth + 1)?;
+                f.write_str("\n")?;
+            }
+            write!(f, "{closing}}}")
+        }
+        other => write!(f, "{other:?}"),
+    }
+}